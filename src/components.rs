@@ -12,13 +12,89 @@ pub struct Selected;
 #[derive(Component)]
 pub struct InMotion;
 
+/// InMotion given only while the particle is hovered in solo-on-hover mode (removed with it on un-hover)
+#[derive(Component)]
+pub struct SoloMotion;
+
+/// Extra Motion1 starting angle (radians), added on the particle's first orbit step and then removed
+/// Staggered phases turn the lockstep orbit into a traveling wave around the ring
+#[derive(Component)]
+pub struct OrbitPhase(pub f32);
+
+/// Material a particle shows on its own (restored when a tint such as Locked is removed)
+#[derive(Component, Clone)]
+pub struct ParticleBaseColor(pub Handle<StandardMaterial>);
+
+/// Newly created particle, pulses its emissive until the timer runs out
+#[derive(Component)]
+pub struct JustCreated {
+    pub timer: Timer,
+}
+
+impl Default for JustCreated {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(crate::constants::JUST_CREATED_PULSE_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+/// Particle that expires: counts down and is despawned when it reaches zero
+#[derive(Component)]
+pub struct Lifetime {
+    pub remaining: f32, // Seconds left before the particle is removed
+}
+
+/// Outline shell shown around a selected particle (child of the particle)
+#[derive(Component)]
+pub struct SelectionOutline;
+
+/// Appearance of the selection outline
+#[derive(Resource)]
+pub struct SelectionStyle {
+    pub outline_color: Color,
+    pub outline_scale: f32, // Shell size relative to the particle
+}
+
+impl Default for SelectionStyle {
+    fn default() -> Self {
+        Self {
+            outline_color: crate::constants::SELECTION_OUTLINE_COLOR,
+            outline_scale: crate::constants::SELECTION_OUTLINE_SCALE,
+        }
+    }
+}
+
+/// Particle velocity (meters per second) used by physics-driven motion
+#[derive(Component, Clone, Copy, Default)]
+pub struct Velocity(pub Vec3);
+
+/// Pinned particle: ignored by motion, group transform and selection transform
+#[derive(Component)]
+pub struct Locked;
+
 #[derive(Component)]
 pub struct SelectionBoundingBox;
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct CameraViewChanged {
     pub needs_reset: bool,
     pub entity: Option<Entity>,
+    pub target: Option<Transform>, // Pose the camera is easing toward (None when idle)
+    pub smooth_views: bool, // Ease view buttons toward their pose instead of snapping
+    pub smoothing_rate: f32, // Exponential approach rate (1/s), higher settles faster
+}
+
+impl Default for CameraViewChanged {
+    fn default() -> Self {
+        Self {
+            needs_reset: false,
+            entity: None,
+            target: None,
+            smooth_views: true,
+            smoothing_rate: crate::constants::CAMERA_VIEW_SMOOTHING_RATE,
+        }
+    }
 }
 
 #[derive(Component)]
@@ -26,9 +102,33 @@ pub struct TrajectoryCircle {
     pub particle_entity: Entity,
 }
 
+/// Static copy of a trajectory circle or trail, kept until the snapshots are cleared
+#[derive(Component)]
+pub struct FrozenTrajectory;
+
 #[derive(Resource, Default)]
 pub struct ParticleSelectionState {
     pub selected_particles: std::collections::HashSet<Entity>,
+    pub clear_requested: bool,  // Deselect everything (set by the Clear Selection button)
+}
+
+impl ParticleSelectionState {
+    /// Selected entities in a stable order (sorted by entity)
+    /// The set itself is unordered; use this where the result depends on the order
+    /// (per-index values, seeded randomness, exports) so it does not change between frames
+    pub fn sorted(&self) -> Vec<Entity> {
+        let mut selected: Vec<Entity> = self.selected_particles.iter().copied().collect();
+        selected.sort_unstable();
+        selected
+    }
+}
+
+/// Sent by the selection systems whenever `ParticleSelectionState` is modified
+/// Carries the entities that entered and left the selection
+#[derive(Message, Default, Clone, Debug)]
+pub struct SelectionChanged {
+    pub added: Vec<Entity>,
+    pub removed: Vec<Entity>,
 }
 
 #[derive(Resource, Default)]
@@ -37,25 +137,247 @@ pub struct ParticlePositions {
     pub current_positions: std::collections::HashMap<Entity, Vec3>, // Current world positions
 }
 
+impl ParticlePositions {
+    /// Number of particles currently in the scene (including ones spawned this frame)
+    pub fn particle_count(&self) -> usize {
+        self.current_positions.len()
+    }
+}
+
+/// Upper limit on the total number of particles
+#[derive(Resource)]
+pub struct MaxParticles {
+    pub limit: usize,
+    pub clamped: bool, // Last creation request was cut short by the limit
+}
+
+impl MaxParticles {
+    /// Particles that can still be added under the cap; `total` counts the existing particles
+    /// and the queued spawns, which take their place in the budget before they appear
+    pub fn remaining(&self, total: usize) -> usize {
+        self.limit.saturating_sub(total)
+    }
+}
+
+impl Default for MaxParticles {
+    fn default() -> Self {
+        Self {
+            limit: crate::constants::DEFAULT_MAX_PARTICLES,
+            clamped: false,
+        }
+    }
+}
+
+/// Particles spawned at startup (stress mode), seeded from the WEB_PARTICLE_COUNT environment variable
+/// so large scenes can be profiled without recompiling; 0 starts with an empty scene
+#[derive(Resource, Default)]
+pub struct StartupParticleCount(pub usize);
+
+impl StartupParticleCount {
+    /// Read the count from the environment; unset or unparsable values give 0 (with a warning for the latter)
+    pub fn from_env() -> Self {
+        let Ok(value) = std::env::var(crate::constants::STARTUP_PARTICLE_COUNT_ENV) else {
+            return Self(0);
+        };
+        match value.trim().parse() {
+            Ok(count) => Self(count),
+            Err(_) => {
+                warn!("Ignoring {}={:?}: not a particle count", crate::constants::STARTUP_PARTICLE_COUNT_ENV, value);
+                Self(0)
+            }
+        }
+    }
+}
+
 #[derive(Resource)]
 pub struct ParticleBoundsState {
-    pub bounds_x: f32,  // Total size in X direction (meters) - diameter, not half-width
-    pub bounds_z: f32,  // Total size in Z direction (meters) - diameter, not half-width
-    pub bounds_y_height: f32,  // Y height/range starting from 1.0 (meters)
-    pub previous_bounds_x: f32,
-    pub previous_bounds_z: f32,
-    pub previous_bounds_y_height: f32,
+    pub min: Vec3,  // Lower corner of the distribution region (meters)
+    pub max: Vec3,  // Upper corner of the distribution region (meters)
+    pub previous_min: Vec3,
+    pub previous_max: Vec3,
+    pub fit_requested: bool,  // Shrink/grow the bounds to enclose every particle
+    pub fit_grid: bool,  // Also resize the grid to cover the fitted bounds
+}
+
+impl ParticleBoundsState {
+    /// Map a normalized (0-1) base position to world coordinates within [min, max]
+    pub fn normalized_to_world(&self, normalized: Vec3) -> Vec3 {
+        self.min + normalized * (self.max - self.min)
+    }
+    
+    /// Inverse of normalized_to_world (degenerate bound sizes map to the center)
+    pub fn world_to_normalized(&self, world: Vec3) -> Vec3 {
+        let size = self.max - self.min;
+        let normalize = |value: f32, size: f32| if size > 0.0 { value / size } else { 0.5 };
+        let relative = world - self.min;
+        Vec3::new(
+            normalize(relative.x, size.x),
+            normalize(relative.y, size.y),
+            normalize(relative.z, size.z),
+        )
+    }
+    
+    /// Clamp a world position into the bounds volume
+    pub fn clamp_world(&self, world: Vec3) -> Vec3 {
+        world.clamp(self.min.min(self.max), self.max.max(self.min))
+    }
 }
 
 impl Default for ParticleBoundsState {
+    fn default() -> Self {
+        // Centered on the origin in X/Z (total size matches the grid), Y range 1.0 to 2.0
+        let half_size = crate::constants::PARTICLE_GRID_BOUNDS * 0.5;
+        let min = Vec3::new(-half_size, 1.0, -half_size);
+        let max = Vec3::new(half_size, 2.0, half_size);
+        Self {
+            min,
+            max,
+            previous_min: min,
+            previous_max: max,
+            fit_requested: false,
+            fit_grid: false,
+        }
+    }
+}
+
+/// Seeded random number generator so random placement is reproducible
+#[derive(Resource)]
+pub struct ParticleRng {
+    pub rng: rand::rngs::StdRng,
+}
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self::from_seed(crate::constants::PARTICLE_RNG_SEED)
+    }
+}
+
+/// Cached summary of the current selection (recomputed when selection or positions change)
+#[derive(Resource, Default, PartialEq)]
+pub struct SelectionStats {
+    pub count: usize,
+    pub centroid: Vec3,
+    pub min: Vec3,
+    pub max: Vec3,
+    pub radius: f32, // Largest distance from the centroid to a selected particle
+}
+
+/// Shape used to visualize the selection bounds
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionBoundsShape {
+    #[default]
+    Box,
+    Sphere,
+}
+
+#[derive(Resource, Default)]
+pub struct SelectionBoundsState {
+    pub shape: SelectionBoundsShape,
+    pub show_centroid: bool, // Draw a crosshair at the selection centroid
+}
+
+/// Brush selection tool: a left click selects every particle within `radius` (measured in XZ) of the
+/// point where the cursor ray hits the ground plane
+#[derive(Resource)]
+pub struct BrushSelectState {
+    pub is_active: bool,
+    pub radius: f32, // Brush radius (meters)
+}
+
+impl Default for BrushSelectState {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            radius: crate::constants::DEFAULT_BRUSH_RADIUS,
+        }
+    }
+}
+
+/// Grow/shrink the selection by proximity: grow adds unselected particles within `radius` of a
+/// selected one, shrink drops selected particles with an unselected particle within `radius`
+#[derive(Resource)]
+pub struct SelectionGrowState {
+    pub radius: f32, // Neighbor distance (meters)
+    pub grow_requested: bool,
+    pub shrink_requested: bool,
+}
+
+impl Default for SelectionGrowState {
     fn default() -> Self {
         Self {
-            bounds_x: 10.0,  // Total size: 10m (matches grid)
-            bounds_z: 10.0,  // Total size: 10m (matches grid)
-            bounds_y_height: 1.0,  // Height: 1.0m (Y range: 1.0 to 2.0)
-            previous_bounds_x: 10.0,
-            previous_bounds_z: 10.0,
-            previous_bounds_y_height: 1.0,
+            radius: crate::constants::DEFAULT_SELECTION_GROW_RADIUS,
+            grow_requested: false,
+            shrink_requested: false,
+        }
+    }
+}
+
+/// Place-at-cursor tool: a left click spawns one particle where the cursor ray hits
+/// the horizontal plane at `plane_height`
+/// With `spray` enabled, holding the left button places `spray_rate` particles per second instead
+#[derive(Resource)]
+pub struct PlaceAtCursorState {
+    pub is_active: bool,
+    pub plane_height: f32, // Height of the placement plane (meters)
+    pub spray: bool,
+    pub spray_rate: f32, // Particles per second while spraying
+}
+
+impl Default for PlaceAtCursorState {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            plane_height: 0.0,
+            spray: false,
+            spray_rate: crate::constants::DEFAULT_SPRAY_RATE,
+        }
+    }
+}
+
+/// Particle positions waiting to be spawned, streamed a chunk per frame
+#[derive(Resource)]
+pub struct PendingSpawns {
+    pub positions: std::collections::VecDeque<Vec3>,
+    pub streaming: bool, // Queue creation requests instead of spawning in one frame
+    pub chunk_size: usize, // Particles spawned per frame while streaming
+}
+
+impl Default for PendingSpawns {
+    fn default() -> Self {
+        Self {
+            positions: std::collections::VecDeque::new(),
+            streaming: false,
+            chunk_size: crate::constants::SPAWN_CHUNK_SIZE,
+        }
+    }
+}
+
+impl ParticleRng {
+    pub fn from_seed(seed: u64) -> Self {
+        use rand::SeedableRng;
+        Self {
+            rng: rand::rngs::StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+/// Point the selection scale is applied about
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScalePivot {
+    #[default]
+    Centroid, // Mean of the selection's original positions
+    Origin, // World origin (radial scaling)
+    Custom, // SelectionTransformState::custom_pivot
+}
+
+impl ScalePivot {
+    pub const ALL: [ScalePivot; 3] = [ScalePivot::Centroid, ScalePivot::Origin, ScalePivot::Custom];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            ScalePivot::Centroid => "Centroid",
+            ScalePivot::Origin => "World Origin",
+            ScalePivot::Custom => "Custom Point",
         }
     }
 }
@@ -67,7 +389,13 @@ pub struct SelectionTransformState {
     pub previous_position_offset: Vec3,
     pub previous_scale: Vec3,
     pub original_selection_positions: std::collections::HashMap<Entity, Vec3>,  // Store original positions when selection changes
-    pub previous_selection_hash: u64,  // Hash of selection to detect changes
+    pub flatten_requested: Option<usize>,  // Axis index (0 = X, 1 = Y, 2 = Z) to flatten the selection onto
+    pub scatter_requested: bool,  // Re-randomize selected particles within the particle bounds
+    pub nudge_step: f32,  // Arrow-key nudge distance (meters), multiplied while Shift is held
+    pub exact_position_requested: Option<Vec3>,  // Move the single selected particle to this position
+    pub apply_requested: bool,  // Bake the current offset/scale into the particles' positions
+    pub scale_pivot: ScalePivot,  // Point the scale is applied about (kept across selection changes)
+    pub custom_pivot: Vec3,  // Pivot used when scale_pivot is Custom
 }
 
 impl Default for SelectionTransformState {
@@ -78,7 +406,24 @@ impl Default for SelectionTransformState {
             previous_position_offset: Vec3::ZERO,
             previous_scale: Vec3::ONE,
             original_selection_positions: std::collections::HashMap::new(),
-            previous_selection_hash: 0,
+            flatten_requested: None,
+            scatter_requested: false,
+            nudge_step: 0.05,
+            exact_position_requested: None,
+            apply_requested: false,
+            scale_pivot: ScalePivot::Centroid,
+            custom_pivot: Vec3::ZERO,
+        }
+    }
+}
+
+impl SelectionTransformState {
+    /// Scale pivot that does not depend on the selection (None for the centroid)
+    pub fn fixed_pivot(&self) -> Option<Vec3> {
+        match self.scale_pivot {
+            ScalePivot::Centroid => None,
+            ScalePivot::Origin => Some(Vec3::ZERO),
+            ScalePivot::Custom => Some(self.custom_pivot),
         }
     }
 }
@@ -86,18 +431,20 @@ impl Default for SelectionTransformState {
 #[derive(Resource)]
 pub struct ParticleGroupState {
     pub offset: Vec3,  // Global offset for all particles (for moving as group)
-    pub scale: f32,    // Scale factor for resizing (1.0 = no scaling)
+    pub scale: Vec3,   // Per-axis scale factor for resizing (1.0 = no scaling)
     pub previous_offset: Vec3,  // For change detection
-    pub previous_scale: f32,  // For change detection
+    pub previous_scale: Vec3,  // For change detection
+    pub uniform_scale: bool,  // UI ties the three scale axes together
 }
 
 impl Default for ParticleGroupState {
     fn default() -> Self {
         Self {
             offset: Vec3::ZERO,
-            scale: 1.0,
+            scale: Vec3::ONE,
             previous_offset: Vec3::ZERO,
-            previous_scale: 1.0,
+            previous_scale: Vec3::ONE,
+            uniform_scale: true,
         }
     }
 }
@@ -106,6 +453,13 @@ impl Default for ParticleGroupState {
 pub struct Motion1State {
     pub is_active: bool,
     pub rotation_speed: f32, // radians per second
+    pub rotation_axis: Vec3, // Orbit axis through the rotation center (Y = flat XZ orbit)
+    pub rotation_center: Vec3, // Explicit orbit pivot (used unless use_group_offset is set)
+    pub use_group_offset: bool, // Orbit around the XZ of the group offset instead
+    pub stagger_phases: bool, // Offset each started particle's angle by its index
+    pub phase_spread: f32, // Total phase (radians) spread across the started particles
+    pub tint_in_motion: bool, // Give particles with InMotion an emissive tint
+    pub solo_on_hover: bool, // Orbit just the hovered particle while the cursor is over it
 }
 
 impl Default for Motion1State {
@@ -113,23 +467,618 @@ impl Default for Motion1State {
         Self {
             is_active: false,
             rotation_speed: 1.0, // 1 radian per second (about 57 degrees per second)
+            rotation_axis: Vec3::Y,
+            rotation_center: Vec3::ZERO,
+            use_group_offset: true,
+            stagger_phases: false,
+            phase_spread: std::f32::consts::PI,
+            tint_in_motion: false,
+            solo_on_hover: false,
+        }
+    }
+}
+
+impl Motion1State {
+    /// Normalized orbit axis, falling back to Y when the configured axis is degenerate
+    pub fn axis(&self) -> Vec3 {
+        self.rotation_axis.normalize_or(Vec3::Y)
+    }
+    
+    /// Starting phase for the index-th of count particles started together
+    pub fn phase_for(&self, index: usize, count: usize) -> f32 {
+        if count == 0 {
+            return 0.0;
+        }
+        self.phase_spread * index as f32 / count as f32
+    }
+}
+
+/// Particle driven by the wave motion; stores the height it ripples around
+/// Wave motion takes precedence over Motion1, which skips these particles
+#[derive(Component)]
+pub struct WaveMotion {
+    pub base_y: f32,
+}
+
+/// Ripple motion parameters (heights oscillate with XZ distance from the center)
+#[derive(Resource)]
+pub struct WaveMotionState {
+    pub amplitude: f32, // meters
+    pub wavelength: f32, // meters between crests
+    pub speed: f32, // radians per second
+    pub center: Vec2, // XZ
+    pub toggle_requested: bool,
+    pub active_count: usize, // Particles currently in wave motion
+}
+
+impl Default for WaveMotionState {
+    fn default() -> Self {
+        Self {
+            amplitude: 0.3,
+            wavelength: 2.0,
+            speed: 2.0,
+            center: Vec2::ZERO,
+            toggle_requested: false,
+            active_count: 0,
+        }
+    }
+}
+
+/// Random-walk motion: selected particles take small seeded random steps each frame
+#[derive(Resource)]
+pub struct JitterMotionState {
+    pub is_active: bool,
+    pub step_size: f32, // Maximum step per axis per frame (meters)
+}
+
+impl Default for JitterMotionState {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            step_size: 0.02,
+        }
+    }
+}
+
+/// How the attractor force scales with distance
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AttractorFalloff {
+    InverseDistance, // Stronger close to the attractor
+    Linear, // Stronger far from the attractor (spring-like)
+}
+
+/// Attract/repel force pulling selected particles toward (or pushing them from) a point
+#[derive(Resource)]
+pub struct AttractorState {
+    pub is_active: bool,
+    pub position: Vec3,
+    pub strength: f32,
+    pub repel: bool,
+    pub falloff: AttractorFalloff,
+    pub max_speed: f32, // meters per second
+}
+
+impl Default for AttractorState {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            position: Vec3::new(0.0, 1.5, 0.0),
+            strength: 2.0,
+            repel: false,
+            falloff: AttractorFalloff::InverseDistance,
+            max_speed: 3.0,
+        }
+    }
+}
+
+/// Uniform hash grid over particle positions for neighbor queries
+/// Rebuilt from ParticlePositions whenever they change
+#[derive(Resource)]
+pub struct SpatialGrid {
+    pub cell_size: f32,
+    pub cells: std::collections::HashMap<IVec3, Vec<(Entity, Vec3)>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self {
+            cell_size: crate::constants::SPATIAL_GRID_CELL_SIZE,
+            cells: std::collections::HashMap::new(),
+        }
+    }
+}
+
+impl SpatialGrid {
+    pub fn cell_of(&self, position: Vec3) -> IVec3 {
+        (position / self.cell_size).floor().as_ivec3()
+    }
+    
+    /// Replace the grid contents with the given positions
+    pub fn rebuild(&mut self, positions: impl IntoIterator<Item = (Entity, Vec3)>) {
+        self.cells.clear();
+        for (entity, position) in positions {
+            let cell = self.cell_of(position);
+            self.cells.entry(cell).or_default().push((entity, position));
+        }
+    }
+    
+    /// Visit every particle within radius of center (including a particle at center itself)
+    pub fn for_each_within(&self, center: Vec3, radius: f32, mut visit: impl FnMut(Entity, Vec3)) {
+        let min_cell = self.cell_of(center - Vec3::splat(radius));
+        let max_cell = self.cell_of(center + Vec3::splat(radius));
+        let radius_squared = radius * radius;
+        for x in min_cell.x..=max_cell.x {
+            for y in min_cell.y..=max_cell.y {
+                for z in min_cell.z..=max_cell.z {
+                    let Some(cell) = self.cells.get(&IVec3::new(x, y, z)) else { continue };
+                    for &(entity, position) in cell {
+                        if position.distance_squared(center) <= radius_squared {
+                            visit(entity, position);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Distance from `entity` (at `center`) to its nearest other particle
+    /// Searches a growing radius, starting at one cell; once the searched block would cover more
+    /// cells than are occupied, all particles are scanned directly instead
+    /// None when the grid holds no other particle
+    pub fn nearest_neighbor_distance(&self, entity: Entity, center: Vec3) -> Option<f32> {
+        let mut radius = self.cell_size;
+        loop {
+            let mut nearest: Option<f32> = None;
+            let mut consider = |other: Entity, position: Vec3| {
+                if other != entity {
+                    let distance = position.distance(center);
+                    nearest = Some(nearest.map_or(distance, |nearest| nearest.min(distance)));
+                }
+            };
+            let cells_per_axis = (2.0 * radius / self.cell_size).ceil() + 1.0;
+            if cells_per_axis.powi(3) > self.cells.len() as f32 {
+                for &(other, position) in self.cells.values().flatten() {
+                    consider(other, position);
+                }
+                return nearest;
+            }
+            self.for_each_within(center, radius, &mut consider);
+            if nearest.is_some() {
+                return nearest;
+            }
+            radius *= 2.0;
+        }
+    }
+}
+
+/// Nearest-neighbor distance statistics over the whole cloud (None with fewer than two particles)
+#[derive(Resource, Default)]
+pub struct NeighborStats {
+    pub mean: Option<f32>,
+    pub min: f32,
+    pub max: f32,
+}
+
+/// Proximity graph: lines between particles closer than the threshold
+#[derive(Resource)]
+pub struct ProximityLinesState {
+    pub is_visible: bool,
+    pub threshold: f32, // Maximum distance between connected particles (meters)
+    pub color: Color,
+    pub max_edges: usize, // Lines drawn per frame at most
+    pub capped: bool, // Set when more edges than max_edges were found last frame
+}
+
+impl Default for ProximityLinesState {
+    fn default() -> Self {
+        Self {
+            is_visible: false,
+            threshold: 0.3,
+            color: crate::constants::PROXIMITY_LINE_COLOR,
+            max_edges: crate::constants::DEFAULT_MAX_PROXIMITY_EDGES,
+            capped: false,
+        }
+    }
+}
+
+/// Particle taking part in the flocking motion
+#[derive(Component)]
+pub struct InFlock;
+
+/// Particle spawned by the emitter, moved by its Velocity each frame
+#[derive(Component)]
+pub struct Emitted;
+
+/// Point emitter spawning particles with an initial velocity (fountains, streams)
+#[derive(Resource)]
+pub struct EmitterState {
+    pub is_active: bool,
+    pub position: Vec3,
+    pub rate: f32, // Particles per second
+    pub direction: Vec3, // Emission direction (normalized when used)
+    pub speed: f32, // Initial speed (meters per second)
+    pub spread_degrees: f32, // Half-angle of the emission cone
+    pub max_count: usize, // Emitted particles alive at once (the global cap still applies)
+}
+
+impl Default for EmitterState {
+    fn default() -> Self {
+        Self {
+            is_active: false,
+            position: Vec3::new(0.0, 0.5, 0.0),
+            rate: 20.0,
+            direction: Vec3::Y,
+            speed: 2.0,
+            spread_degrees: 15.0,
+            max_count: 500,
+        }
+    }
+}
+
+/// Boids flocking parameters
+#[derive(Resource)]
+pub struct FlockingState {
+    pub separation_weight: f32,
+    pub alignment_weight: f32,
+    pub cohesion_weight: f32,
+    pub radius: f32, // Neighborhood radius (meters)
+    pub max_speed: f32, // meters per second
+    pub assign_requested: bool, // Add the current selection to the flock
+    pub clear_requested: bool, // Release every particle from the flock
+}
+
+impl Default for FlockingState {
+    fn default() -> Self {
+        Self {
+            separation_weight: 1.5,
+            alignment_weight: 1.0,
+            cohesion_weight: 1.0,
+            radius: 1.0,
+            max_speed: 2.0,
+            assign_requested: false,
+            clear_requested: false,
+        }
+    }
+}
+
+/// Global particle radius (meters), editable at runtime
+/// Particle meshes are built with PARTICLE_RADIUS and scaled to this value
+#[derive(Resource)]
+pub struct ParticleRadius(pub f32);
+
+impl Default for ParticleRadius {
+    fn default() -> Self {
+        Self(crate::constants::PARTICLE_RADIUS)
+    }
+}
+
+impl ParticleRadius {
+    /// Transform scale to apply to a particle mesh built with PARTICLE_RADIUS
+    pub fn mesh_scale(&self) -> Vec3 {
+        Vec3::splat(self.0 / crate::constants::PARTICLE_RADIUS)
+    }
+}
+
+/// Scalar field particles can be colored by
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorByField {
+    #[default]
+    None,
+    Height,
+    Speed,
+}
+
+impl ColorByField {
+    pub const ALL: [ColorByField; 3] = [ColorByField::None, ColorByField::Height, ColorByField::Speed];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorByField::None => "None",
+            ColorByField::Height => "Height (Y)",
+            ColorByField::Speed => "Speed",
+        }
+    }
+}
+
+/// Colormap used to turn the normalized field value into a color
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Colormap {
+    #[default]
+    Viridis,
+    Turbo,
+    Grayscale,
+}
+
+impl Colormap {
+    pub const ALL: [Colormap; 3] = [Colormap::Viridis, Colormap::Turbo, Colormap::Grayscale];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            Colormap::Viridis => "Viridis",
+            Colormap::Turbo => "Turbo",
+            Colormap::Grayscale => "Grayscale",
+        }
+    }
+}
+
+/// Color-by-field settings; the field range is either taken from the particles or set by hand
+#[derive(Resource)]
+pub struct ColorByState {
+    pub field: ColorByField,
+    pub colormap: Colormap,
+    pub auto_range: bool, // Fit min/max to the current values each frame
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Default for ColorByState {
+    fn default() -> Self {
+        Self {
+            field: ColorByField::None,
+            colormap: Colormap::Viridis,
+            auto_range: true,
+            min: 0.0,
+            max: 1.0,
         }
     }
 }
 
+/// Mesh primitive a particle is drawn with; also stored on each particle
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ParticleShape {
+    #[default]
+    Sphere,
+    Cube,
+    Icosphere,
+    Tetrahedron,
+}
+
+impl ParticleShape {
+    pub const ALL: [ParticleShape; 4] = [ParticleShape::Sphere, ParticleShape::Cube, ParticleShape::Icosphere, ParticleShape::Tetrahedron];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            ParticleShape::Sphere => "Sphere",
+            ParticleShape::Cube => "Cube",
+            ParticleShape::Icosphere => "Icosphere",
+            ParticleShape::Tetrahedron => "Tetrahedron",
+        }
+    }
+    
+    /// Bounding sphere radius of the shape's mesh relative to the particle radius
+    /// The cube has an edge of twice the radius, the other shapes fit inside the radius
+    pub fn bounding_radius_factor(&self) -> f32 {
+        match self {
+            ParticleShape::Cube => 3.0_f32.sqrt(),
+            _ => 1.0,
+        }
+    }
+    
+    /// Mesh built with PARTICLE_RADIUS, like the sphere particles always were
+    pub fn mesh(&self) -> Mesh {
+        let radius = crate::constants::PARTICLE_RADIUS;
+        match self {
+            ParticleShape::Sphere => Sphere::new(radius).into(),
+            ParticleShape::Cube => Cuboid::from_length(radius * 2.0).into(),
+            ParticleShape::Icosphere => Sphere::new(radius).mesh().ico(2).unwrap_or_else(|_| Sphere::new(radius).into()),
+            ParticleShape::Tetrahedron => {
+                // Alternate cube corners give a regular tetrahedron inscribed in the radius
+                let corner = radius / 3.0_f32.sqrt();
+                Tetrahedron::new(
+                    Vec3::new(corner, corner, corner),
+                    Vec3::new(corner, -corner, -corner),
+                    Vec3::new(-corner, corner, -corner),
+                    Vec3::new(-corner, -corner, corner),
+                ).into()
+            }
+        }
+    }
+}
+
+/// Shape used for newly created particles, with one shared mesh per shape
 #[derive(Resource, Default)]
+pub struct ParticleShapeState {
+    pub shape: ParticleShape,
+    pub meshes: std::collections::HashMap<ParticleShape, Handle<Mesh>>,
+}
+
+impl ParticleShapeState {
+    /// Shared mesh for the given shape, created on first use
+    pub fn mesh(&mut self, shape: ParticleShape, meshes: &mut Assets<Mesh>) -> Handle<Mesh> {
+        self.meshes.entry(shape).or_insert_with(|| meshes.add(shape.mesh())).clone()
+    }
+}
+
+/// Named layer a particle belongs to
+#[derive(Component, Clone)]
+pub struct ParticleLayer(pub String);
+
+/// A named layer with its visibility flag and member particles
+pub struct LayerEntry {
+    pub name: String,
+    pub visible: bool,
+    pub members: std::collections::HashSet<Entity>,
+}
+
+#[derive(Resource, Default)]
+pub struct LayerState {
+    pub layers: Vec<LayerEntry>,
+    pub new_layer_name: String, // Text field contents for creating a layer
+    pub create_requested: bool,
+    pub assign_selection_requested: Option<usize>, // Index of the layer to assign the selection to
+    pub visibility_changed: bool,
+}
+
+impl LayerState {
+    /// Drop a particle from all layer bookkeeping (called when particles are removed)
+    pub fn remove_particle(&mut self, entity: Entity) {
+        for layer in self.layers.iter_mut() {
+            layer.members.remove(&entity);
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct VelocityArrowState {
+    pub is_visible: bool,
+    pub length_scale: f32, // Arrow length per unit of speed (meters per m/s)
+}
+
+impl Default for VelocityArrowState {
+    fn default() -> Self {
+        Self {
+            is_visible: false,
+            length_scale: 0.5,
+        }
+    }
+}
+
+#[derive(Resource)]
 pub struct TrajectoryState {
     pub is_visible: bool,
+    pub max_points: usize, // Recorded points kept per trail (oldest dropped first)
+    pub sample_interval: u32, // Record a trail point every N frames
+    pub snapshot_requested: bool, // Freeze copies of the current circles and trails
+    pub clear_snapshots_requested: bool,
+    pub motion_trails: bool, // Short fading trails on selected particles in Motion1 while trajectories are hidden
+}
+
+impl Default for TrajectoryState {
+    fn default() -> Self {
+        Self {
+            is_visible: false,
+            max_points: crate::constants::TRAJECTORY_MAX_POINTS,
+            sample_interval: crate::constants::TRAJECTORY_SAMPLE_INTERVAL,
+            snapshot_requested: false,
+            clear_snapshots_requested: false,
+            motion_trails: false,
+        }
+    }
+}
+
+/// File import/export requests and the last result message
+#[derive(Resource)]
+pub struct IoState {
+    pub trail_export_path: String,
+    pub export_trails_requested: bool,
+    pub scene_path: String, // JSON scene file (array of particles)
+    pub export_scene_requested: bool,
+    pub import_scene_requested: bool,
+    pub status: String, // Result of the last file operation (empty when none yet)
+}
+
+impl Default for IoState {
+    fn default() -> Self {
+        Self {
+            trail_export_path: "trajectory.csv".to_string(),
+            export_trails_requested: false,
+            scene_path: "scene.json".to_string(),
+            export_scene_requested: false,
+            import_scene_requested: false,
+            status: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlaybackMode {
+    #[default]
+    Live,
+    Recording,
+    Replaying, // Live motion is paused and particles show the recorded frames
+}
+
+/// Positions of every particle at one recorded instant
+pub struct PlaybackFrame {
+    pub time: f32, // Seconds since the recording started
+    pub positions: Vec<(Entity, Vec3)>,
+}
+
+/// Record and replay of particle motion
+/// Frames are recorded every `sample_interval` frames until `duration` seconds, `max_frames` frames
+/// or `max_positions` stored positions, whichever comes first; the position budget bounds the memory
+/// a recording takes, so many particles get a shorter recording
+#[derive(Resource)]
+pub struct PlaybackState {
+    pub mode: PlaybackMode,
+    pub frames: Vec<PlaybackFrame>,
+    pub duration: f32, // Longest recording (seconds)
+    pub max_frames: usize,
+    pub max_positions: usize, // Budget of positions over all frames
+    pub recorded_positions: usize, // Positions stored in `frames`
+    pub sample_interval: u32, // Record a frame every N frames
+    pub playhead: f32, // Replay position (seconds into the recording)
+    pub is_playing: bool, // Playhead advances with time (otherwise it is only scrubbed)
+    pub live_positions: Vec<(Entity, Vec3)>, // Positions before replay, restored when it is exited
+    pub record_requested: bool,
+    pub stop_requested: bool, // Ends a recording early
+    pub replay_requested: bool,
+    pub exit_replay_requested: bool,
+}
+
+impl PlaybackState {
+    /// Length of the recording (time of its last frame)
+    pub fn recorded_seconds(&self) -> f32 {
+        self.frames.last().map(|frame| frame.time).unwrap_or(0.0)
+    }
+}
+
+impl Default for PlaybackState {
+    fn default() -> Self {
+        Self {
+            mode: PlaybackMode::Live,
+            frames: Vec::new(),
+            duration: crate::constants::PLAYBACK_DEFAULT_SECONDS,
+            max_frames: crate::constants::PLAYBACK_MAX_FRAMES,
+            max_positions: crate::constants::PLAYBACK_MAX_POSITIONS,
+            recorded_positions: 0,
+            sample_interval: 1,
+            playhead: 0.0,
+            is_playing: false,
+            live_positions: Vec::new(),
+            record_requested: false,
+            stop_requested: false,
+            replay_requested: false,
+            exit_replay_requested: false,
+        }
+    }
+}
+
+/// Recorded path of a particle as (elapsed seconds, world position), oldest first
+#[derive(Component, Default)]
+pub struct TrajectoryTrail {
+    pub points: std::collections::VecDeque<(f32, Vec3)>,
+}
+
+/// Appearance of the trajectory circles
+#[derive(Resource)]
+pub struct TrajectoryStyle {
+    pub thickness: f32, // Torus minor radius (meters)
+    pub color: Color,
+}
+
+impl Default for TrajectoryStyle {
+    fn default() -> Self {
+        Self {
+            thickness: crate::constants::TRAJECTORY_CIRCLE_THICKNESS,
+            color: crate::constants::TRAJECTORY_COLOR,
+        }
+    }
 }
 
 #[derive(Component)]
 pub struct SelectionBox;
 
+/// Text node next to the cursor showing the selection box size while dragging
+#[derive(Component)]
+pub struct SelectionBoxLabel;
+
 #[derive(Resource, Default)]
 pub struct SelectionBoxState {
     pub is_active: bool,
     pub start_position: Option<Vec2>,
     pub current_position: Option<Vec2>,
+    pub show_count_preview: bool,  // Also count the particles inside the box (projects every particle each frame)
 }
 
 #[derive(Resource, Default)]
@@ -138,6 +1087,7 @@ pub struct MouseButtonState {
     pub right_pressed: bool,
     pub left_was_pressed: bool,
     pub right_was_pressed: bool,
+    pub left_press_position: Option<Vec2>, // Cursor position when the left button went down
 }
 
 
@@ -148,16 +1098,53 @@ pub struct RightCamera;
 #[derive(Resource)]
 pub struct CameraProjectionState {
     pub last_perspective_fov: f32, // Store FOV for camera projection state
+    pub axis_view_distance: f32, // Distance from the origin used by the axis view buttons
 }
 
 impl Default for CameraProjectionState {
     fn default() -> Self {
         Self {
-            last_perspective_fov: 1.047, // Default ~60 degrees, will be updated from actual camera
+            last_perspective_fov: 60.0_f32.to_radians(), // Default 60 degrees, restored from the config file and updated from the actual camera
+            axis_view_distance: crate::constants::CAMERA_AXIS_VIEW_DISTANCE,
+        }
+    }
+}
+
+/// Limits on where keyboard movement may take the camera
+/// Only movement is clamped, looking around (including straight down) is unaffected
+#[derive(Resource)]
+pub struct CameraConstraintState {
+    pub clamp_above_ground: bool,
+    pub min_height: f32, // Lowest camera Y while the ground clamp is on (meters)
+    pub clamp_to_world: bool,
+    pub world_half_extent: Vec3, // Camera stays within +/- this box around the origin
+}
+
+impl Default for CameraConstraintState {
+    fn default() -> Self {
+        Self {
+            clamp_above_ground: false,
+            min_height: crate::constants::CAMERA_MIN_HEIGHT,
+            clamp_to_world: false,
+            world_half_extent: Vec3::splat(crate::constants::CAMERA_WORLD_HALF_EXTENT),
         }
     }
 }
 
+impl CameraConstraintState {
+    /// Apply the enabled bounds to a camera position
+    pub fn clamp(&self, position: Vec3) -> Vec3 {
+        let mut clamped = position;
+        if self.clamp_to_world {
+            clamped = clamped.clamp(-self.world_half_extent, self.world_half_extent);
+        }
+        if self.clamp_above_ground {
+            clamped.y = clamped.y.max(self.min_height);
+        }
+        clamped
+    }
+}
+
 #[derive(Resource)]
 pub struct EguiLayoutState {
     pub left_panel_end_x: f32, // Actual x position where left panel ends (in logical pixels)
@@ -170,6 +1157,7 @@ pub struct EguiLayoutState {
     pub left_half_panel_collapsed: bool, // Whether the left half panel (middle) is collapsed
     pub d3_viewer_visible: bool, // Whether the 3D viewer is visible (default: true)
     pub plot_center_axes: bool, // Whether to center plot axes to grid dimensions (default: false)
+    pub inspector_show_stats: bool, // Whether the inspector lists scene statistics (default: true)
 }
 
 impl Default for EguiLayoutState {
@@ -185,6 +1173,7 @@ impl Default for EguiLayoutState {
             left_half_panel_collapsed: true, // Start with left panel hidden
             d3_viewer_visible: true, // 3D viewer is visible by default
             plot_center_axes: false, // Start with auto-fit axes
+            inspector_show_stats: true,
         }
     }
 }
@@ -194,15 +1183,43 @@ pub struct StreamsPanelState {
     pub is_visible: bool,
 }
 
-#[derive(Component)]
-pub struct GridLine;
+/// User-facing message shown in the notification stack
+pub struct Notification {
+    pub message: String,
+    pub count: usize, // Times the message was repeated while shown
+    pub remaining: f32, // Seconds until dismissed
+}
+
+/// Timed messages confirming user actions, oldest first (queued with systems::notify)
+#[derive(Resource, Default)]
+pub struct Notifications {
+    pub messages: std::collections::VecDeque<Notification>,
+}
+
+/// Top-down overview inset in the corner of the 3D viewport
+#[derive(Resource)]
+pub struct MinimapState {
+    pub is_visible: bool,
+    pub extent: f32, // Half width of the XZ area shown, centered on the origin (meters)
+    pub size: f32, // Inset side length (logical pixels)
+}
+
+impl Default for MinimapState {
+    fn default() -> Self {
+        Self {
+            is_visible: false,
+            extent: crate::constants::MINIMAP_DEFAULT_EXTENT,
+            size: crate::constants::MINIMAP_DEFAULT_SIZE,
+        }
+    }
+}
 
 #[derive(Resource)]
 pub struct GridState {
     pub size_x: i32, // Grid size in X direction (meters)
     pub size_z: i32, // Grid size in Z direction (meters)
-    pub previous_size_x: i32,
-    pub previous_size_z: i32,
+    pub offset: Vec2, // Grid center on the ground plane (X, Z in meters), purely visual
+    pub follow_selection: bool, // Drive the offset from the selection centroid
 }
 
 impl Default for GridState {
@@ -210,12 +1227,92 @@ impl Default for GridState {
         Self {
             size_x: 10,
             size_z: 10,
-            previous_size_x: 10,
-            previous_size_z: 10,
+            offset: Vec2::ZERO,
+            follow_selection: false,
         }
     }
 }
 
+/// Text label at the positive end of a coordinate axis (UI node placed over the 3D view)
+#[derive(Component)]
+pub struct AxisLabel {
+    pub tip: Vec3, // World position the label follows
+}
+
+#[derive(Resource)]
+pub struct AxisLabelState {
+    pub is_visible: bool,
+}
+
+impl Default for AxisLabelState {
+    fn default() -> Self {
+        Self { is_visible: true }
+    }
+}
+
+/// Mini RGB axes in the bottom-left corner of the 3D viewport showing the camera orientation
+#[derive(Resource)]
+pub struct OrientationGizmoState {
+    pub is_visible: bool,
+}
+
+impl Default for OrientationGizmoState {
+    fn default() -> Self {
+        Self { is_visible: true }
+    }
+}
+
+/// Lighting settings edited from the UI, applied to the directional lights and ambient light
+#[derive(Resource)]
+pub struct LightingState {
+    pub front_light: Option<Entity>,
+    pub back_light: Option<Entity>,
+    pub front_illuminance: f32, // lux
+    pub back_illuminance: f32,  // lux
+    pub light_yaw: f32,   // Front light direction yaw (degrees), back light points the opposite way
+    pub light_pitch: f32, // Front light direction pitch (degrees)
+    pub ambient_brightness: f32,
+}
+
+impl Default for LightingState {
+    fn default() -> Self {
+        Self {
+            front_light: None,
+            back_light: None,
+            front_illuminance: crate::constants::FRONT_LIGHT_ILLUMINANCE,
+            back_illuminance: crate::constants::BACK_LIGHT_ILLUMINANCE,
+            light_yaw: 0.0,
+            light_pitch: 0.0,
+            ambient_brightness: crate::constants::AMBIENT_LIGHT_BRIGHTNESS,
+        }
+    }
+}
+
+/// Render settings edited from the UI
+#[derive(Resource)]
+pub struct RenderSettingsState {
+    pub msaa: Msaa, // Multisample anti-aliasing applied to the cameras
+    pub particle_glow: f32, // Emissive intensity of particle materials, as a fraction of their base color
+}
+
+impl Default for RenderSettingsState {
+    fn default() -> Self {
+        Self {
+            msaa: Msaa::Sample4, // Bevy's default
+            particle_glow: 0.0,
+        }
+    }
+}
+
+/// Marker for the optional ground plane under the grid
+#[derive(Component)]
+pub struct GroundPlane;
+
+#[derive(Resource, Default)]
+pub struct GroundPlaneState {
+    pub is_visible: bool,
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ParticlePlacementMode {
     Random,
@@ -235,6 +1332,16 @@ pub struct ParticleCreationState {
     pub create_requested: bool,
     pub remove_selected_requested: bool,
     pub remove_all_requested: bool,
+    pub remove_all_confirming: bool, // First click on Remove All, waiting for the confirm click
+    pub reset_requested: bool, // Remove every particle, then respawn the startup distribution
+    pub reset_confirming: bool, // First click on Reset Particles, waiting for the confirm click
+    pub duplicate_requested: bool,
+    pub duplicate_offset: Vec3, // Offset applied to duplicated particles (stacks on repeated duplication)
+    pub mirror_copy_requested: Option<usize>, // Axis index (0 = X, 1 = Y, 2 = Z) to mirror a copy of the selection along
+    pub clipboard: Vec<Entity>, // Particles copied with Ctrl+C; replaced by the pasted clones, so repeated pastes stack the offset
+    pub paste_requested: bool, // Clone the clipboard particles at the duplicate offset (Ctrl+V)
+    pub lifetime_enabled: bool, // New particles get a Lifetime and expire
+    pub lifetime_seconds: f32,
 }
 
 impl Default for ParticleCreationState {
@@ -250,6 +1357,16 @@ impl Default for ParticleCreationState {
             create_requested: false,
             remove_selected_requested: false,
             remove_all_requested: false,
+            remove_all_confirming: false,
+            reset_requested: false,
+            reset_confirming: false,
+            duplicate_requested: false,
+            duplicate_offset: Vec3::new(0.5, 0.0, 0.5),
+            mirror_copy_requested: None,
+            clipboard: Vec::new(),
+            paste_requested: false,
+            lifetime_enabled: false,
+            lifetime_seconds: crate::constants::DEFAULT_PARTICLE_LIFETIME,
         }
     }
 }
@@ -6,37 +6,117 @@ use bevy::prelude::*;
 // Particle constants
 pub const PARTICLE_RADIUS: f32 = 0.025;
 pub const PARTICLE_GRID_BOUNDS: f32 = 10.0; // Default particle distribution bounds (total size: 10m, matches grid)
+pub const BOUNDS_FIT_MARGIN: f32 = 0.1; // Padding around the particles when fitting the bounds (meters)
+pub const PARTICLE_RNG_SEED: u64 = 42; // Seed for reproducible random particle placement
+pub const DEFAULT_MAX_PARTICLES: usize = 10000; // Total particle cap enforced on creation
+pub const STARTUP_PARTICLE_COUNT_ENV: &str = "WEB_PARTICLE_COUNT"; // Stress mode: particles spawned at startup
+pub const SPAWN_CHUNK_SIZE: usize = 100; // Particles spawned per frame when streaming creation
+pub const PLACEMENT_PREVIEW_COLOR: Color = Color::srgba(1.0, 1.0, 0.3, 0.5); // Yellow, spawn volume preview
+pub const DEFAULT_SPRAY_RATE: f32 = 20.0; // Particles per second placed while spraying
+pub const SPRAY_JITTER: f32 = 0.1; // Max horizontal offset of sprayed particles from the cursor (meters)
+pub const JUST_CREATED_PULSE_SECONDS: f32 = 1.0; // How long new particles pulse after spawning
+pub const JUST_CREATED_PULSE_HZ: f32 = 3.0; // Pulses per second
+pub const JUST_CREATED_EMISSIVE: LinearRgba = LinearRgba::rgb(1.0, 0.9, 0.4); // Warm glow at full pulse
+pub const DEFAULT_PARTICLE_LIFETIME: f32 = 5.0; // Seconds a particle lives when spawned with a lifetime
+pub const LIFETIME_FADE_SECONDS: f32 = 1.0; // Expiring particles fade out over their last seconds
+pub const SPATIAL_GRID_CELL_SIZE: f32 = 0.5; // Spatial hash cell edge length (meters)
+pub const NEIGHBOR_STATS_INTERVAL_SECONDS: f32 = 0.5; // Nearest-neighbor statistics are recomputed at this interval
+pub const HIDDEN_COUNT_INTERVAL_SECONDS: f64 = 0.5; // The Inspector recounts hidden particles at this interval
 
 // Grid constants
 pub const GRID_SPACING: f32 = 1.0;
-pub const GRID_LINE_RADIUS: f32 = 0.005;
 pub const GRID_COLOR: Color = Color::srgb(0.5, 0.5, 0.5); // Mid gray
+pub const GROUND_PLANE_COLOR: Color = Color::srgba(0.25, 0.25, 0.25, 0.5); // Semi-transparent gray
 
 // Axis constants
 pub const AXIS_LENGTH: f32 = 5.0;
-pub const AXIS_RADIUS: f32 = 0.01;
+pub const AXIS_LABEL_FONT_SIZE: f32 = 16.0;
+pub const AXIS_LABEL_OFFSET: f32 = 4.0; // Screen offset of a label from its axis tip (logical pixels)
+pub const ORIENTATION_GIZMO_MARGIN: f32 = 50.0; // Distance of the orientation gizmo from the viewport corner (logical pixels)
+pub const ORIENTATION_GIZMO_SIZE: f32 = 35.0; // On-screen axis length of the orientation gizmo (logical pixels)
 
 // Camera constants
-pub const CAMERA_FRONT_POSITION: Vec3 = Vec3::new(0.0, 0.0, 15.0);
-pub const CAMERA_TOP_POSITION: Vec3 = Vec3::new(0.0, 15.0, 0.0);
+pub const CAMERA_AXIS_VIEW_DISTANCE: f32 = 15.0; // Default distance of the Front/Back/Left/Right/Top/Bottom views from the origin
 pub const CAMERA_START_POSITION: Vec3 = Vec3::new(9.0, 7.0, 15.0);
+pub const CAMERA_MIN_HEIGHT: f32 = 0.1; // Lowest camera Y when the ground clamp is on (meters)
+pub const CAMERA_WORLD_HALF_EXTENT: f32 = 200.0; // Default half size of the camera world bounds box (meters)
+pub const FRAME_SELECTION_PADDING: f32 = 1.2; // Framed sphere radius multiplier, leaves a margin around the selection
+pub const FRAME_SELECTION_MIN_RADIUS: f32 = 0.5; // Smallest framed radius (a single particle has radius 0)
+pub const CAMERA_VIEW_SMOOTHING_RATE: f32 = 8.0; // Default easing rate (1/s) for the view buttons
+pub const CAMERA_VIEW_SETTLE_DISTANCE: f32 = 0.01; // Easing finishes once this close to the target (meters)
+pub const CAMERA_VIEW_SETTLE_ANGLE: f32 = 0.001; // ...and within this angle of the target rotation (radians)
+pub const FOV_MIN_DEGREES: f32 = 30.0; // Perspective FOV slider range, also clamps restored values
+pub const FOV_MAX_DEGREES: f32 = 120.0;
+
+// Config constants
+pub const CONFIG_FILE_PATH: &str = "web-particle.cfg"; // Settings persisted between sessions (key = value lines)
 
 // Material colors
 pub const COLOR_WHITE: Color = Color::srgb(1.0, 1.0, 1.0);
 pub const COLOR_RED: Color = Color::srgb(1.0, 0.0, 0.0);
 pub const COLOR_GREEN: Color = Color::srgb(0.0, 1.0, 0.0);
 pub const COLOR_BLUE: Color = Color::srgb(0.0, 0.0, 1.0);
+pub const MOTION_TINT_EMISSIVE: LinearRgba = LinearRgba::rgb(0.15, 0.35, 0.6); // Subtle cool glow on particles in Motion1
+pub const PARTICLE_GLOW_MAX: f32 = 1.0; // Glow slider limit; at 1 a particle emits its own base color, still below the unlit selection shell
+pub const COLOR_LOCKED: Color = Color::srgb(0.3, 0.4, 0.7); // Dim blue tint for locked particles
 
 // Lighting constants
 pub const FRONT_LIGHT_ILLUMINANCE: f32 = 2000.0;
 pub const BACK_LIGHT_ILLUMINANCE: f32 = 1500.0;
+pub const AMBIENT_LIGHT_BRIGHTNESS: f32 = 80.0; // Matches Bevy's default global ambient light
 
 // Trajectory visualization constants
 pub const TRAJECTORY_CIRCLE_THICKNESS: f32 = 0.01;
 pub const TRAJECTORY_COLOR: Color = Color::srgba(0.0, 1.0, 1.0, 0.6); // Cyan with transparency
+pub const TRAJECTORY_MAX_POINTS: usize = 500; // Default trail length
+pub const TRAJECTORY_SAMPLE_INTERVAL: u32 = 2; // Default frames between trail points
+pub const PLAYBACK_DEFAULT_SECONDS: f32 = 10.0; // Default record and replay length
+pub const PLAYBACK_MAX_FRAMES: usize = 1200; // Default frame cap of a recording
+pub const PLAYBACK_MAX_POSITIONS: usize = 4_000_000; // Positions (frames x particles) a recording may hold, about 100 MB
+pub const MOTION_TRAIL_POINTS: usize = 20; // Frames kept by the short motion trails
+pub const FROZEN_TRAJECTORY_ALPHA: f32 = 0.35; // Snapshots use the trajectory color at this opacity
+
+// Motion constants
+pub const MOTION1_MIN_RADIUS: f32 = 0.001; // Particles closer than this to the orbit axis stay still
+pub const MOTION1_CENTER_COLOR: Color = Color::srgb(1.0, 0.5, 0.1); // Orange, orbit pivot marker
+pub const MOTION1_CENTER_MARKER_SIZE: f32 = 0.1; // Pivot marker radius (meters)
+
+// Gizmo constants
+pub const GIZMO_RENDER_LAYER: usize = 1; // Gizmos render only on the 3D camera, not the egui overlay
+
+// Velocity arrow constants
+pub const VELOCITY_ARROW_COLOR: Color = Color::srgb(1.0, 0.6, 0.1); // Orange
+pub const VELOCITY_ARROW_MIN_SPEED: f32 = 0.01; // Speeds below this draw no arrow
+
+// Attractor constants
+pub const ATTRACTOR_COLOR: Color = Color::srgb(1.0, 0.2, 0.8); // Magenta
+pub const ATTRACTOR_MIN_DISTANCE: f32 = 0.1; // Inverse-distance force is capped inside this distance
+
+// Proximity line constants
+pub const PROXIMITY_LINE_COLOR: Color = Color::srgba(0.6, 0.8, 1.0, 0.6); // Pale blue
+pub const DEFAULT_MAX_PROXIMITY_EDGES: usize = 5000;
+
+// Color-by-field constants
+pub const COLORMAP_BINS: usize = 32; // Shared materials per colormap (values are quantized to these)
+pub const COLOR_LEGEND_SIZE: [f32; 2] = [16.0, 140.0]; // Inspector legend bar width and height (logical pixels)
+pub const COLOR_LEGEND_STEPS: usize = 64; // Gradient slices drawn in the legend bar
+
+// Emitter constants
+pub const EMITTER_COLOR: Color = Color::srgb(0.2, 0.9, 1.0); // Cyan
+pub const EMITTER_MARKER_RADIUS: f32 = 0.1;
 
 // Selection box constants
 pub const SELECTION_BOX_COLOR: Color = Color::srgba(0.2, 0.5, 1.0, 0.2); // Semi-transparent blue
+pub const SELECTION_BOX_LABEL_FONT_SIZE: f32 = 12.0;
+pub const SELECTION_BOX_LABEL_OFFSET: Vec2 = Vec2::new(12.0, 12.0); // Label position relative to the cursor (logical pixels)
+pub const BRUSH_COLOR: Color = Color::srgb(1.0, 0.8, 0.2); // Amber, brush select cursor
+pub const DEFAULT_BRUSH_RADIUS: f32 = 0.5; // Brush select radius (meters)
+pub const DEFAULT_SELECTION_GROW_RADIUS: f32 = 0.3; // Grow/Shrink Selection neighbor distance (meters)
+pub const SELECTION_OUTLINE_COLOR: Color = Color::srgb(0.0, 1.0, 0.0); // Green
+pub const SELECTION_OUTLINE_SCALE: f32 = 1.3;
+pub const NUDGE_FAST_MULTIPLIER: f32 = 10.0; // Nudge step multiplier while Shift is held
+pub const CENTROID_MARKER_SIZE: f32 = 0.15; // Half-length of the centroid crosshair arms (meters)
+pub const MIN_DRAG_DISTANCE: f32 = 5.0; // Logical pixels; shorter cursor movement counts as a click
 
 // World background color
 pub const WORLD_BACKGROUND_COLOR: Color = Color::srgb(0.08, 0.08, 0.08); // Very dark, almost pure black
@@ -45,3 +125,12 @@ pub const WORLD_BACKGROUND_COLOR: Color = Color::srgb(0.08, 0.08, 0.08); // Very
 pub const EGUI_TOP_BAR_HEIGHT: f32 = 20.0;
 pub const EGUI_SECOND_TOP_BAR_HEIGHT: f32 = 22.0; // 2px for the buttons
 pub const EGUI_LEFT_PANEL_WIDTH: f32 = 200.0;
+pub const NOTIFICATION_SECONDS: f32 = 4.0; // How long a notification stays up
+pub const NOTIFICATION_FADE_SECONDS: f32 = 0.5; // Notifications fade out over their last half second
+pub const NOTIFICATION_MAX_VISIBLE: usize = 5; // Older notifications are dropped beyond this
+pub const NOTIFICATION_WIDTH: f32 = 260.0; // Notification stack width (logical pixels)
+pub const MINIMAP_DEFAULT_EXTENT: f32 = 10.0; // Half width of the area the minimap shows (meters)
+pub const MINIMAP_DEFAULT_SIZE: f32 = 160.0; // Minimap side length (logical pixels)
+pub const MINIMAP_MARGIN: f32 = 8.0; // Gap between the minimap and the viewport corner (logical pixels)
+pub const MINIMAP_DOT_SIZE: f32 = 2.0; // Particle dot size; particles sharing a dot cell are drawn once (logical pixels)
+pub const MINIMAP_FRUSTUM_LENGTH: f32 = 0.5; // Frustum indicator length as a fraction of the minimap extent
@@ -0,0 +1,10 @@
+// lib.rs
+// Copyright (C) 2026 vecnode
+
+pub mod components;
+pub mod constants;
+pub mod plugins;
+pub mod setup;
+pub mod systems;
+
+pub use plugins::{ParticlePlugin, CameraPlugin, ScenePlugin, UiPlugin};
@@ -0,0 +1,123 @@
+// plugins/camera.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use bevy::camera::Viewport;
+use bevy::camera::visibility::RenderLayers;
+use crate::components::{CameraViewChanged, CameraProjectionState, CameraConstraintState, EguiLayoutState, RightCamera};
+use crate::constants::{CAMERA_START_POSITION, GIZMO_RENDER_LAYER};
+use crate::systems::{load_app_config, save_app_config, smooth_camera_view_change, reset_viewport_constrained_camera_after_view_change};
+use super::viewport_constrained_camera::{ViewportConstrainedCameraPlugin, ViewportConstrainedCamera, ViewportConstrainedCameraState};
+
+/// Plugin for the 3D camera: spawning, viewport layout next to the panels, controls and view resets
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(ViewportConstrainedCameraPlugin)
+            .init_resource::<CameraViewChanged>()
+            .init_resource::<CameraProjectionState>()
+            .init_resource::<CameraConstraintState>()
+            // Written by the UI panels, read here to fit the viewport between them
+            .init_resource::<EguiLayoutState>();
+        
+        app.add_systems(
+            Startup,
+            (
+                load_app_config,
+                // The camera starts with the restored FOV
+                setup_split_screen_cameras.after(load_app_config),
+            ),
+        );
+        app.add_systems(Update, (update_camera_viewports, save_app_config, smooth_camera_view_change));
+        app.add_systems(PostUpdate, reset_viewport_constrained_camera_after_view_change);
+    }
+}
+
+fn setup_split_screen_cameras(
+    mut commands: Commands,
+    mut gizmo_config_store: ResMut<GizmoConfigStore>,
+    projection_state: Res<CameraProjectionState>,
+) {
+    // Gizmos (grid, axes, overlays) go on their own layer so only the 3D camera draws them
+    let (gizmo_config, _) = gizmo_config_store.config_mut::<DefaultGizmoConfigGroup>();
+    gizmo_config.render_layers = RenderLayers::layer(GIZMO_RENDER_LAYER);
+    
+    // Single camera for 3D world (will take remaining space on right)
+    commands.spawn((
+        Camera3d::default(),
+        Camera {
+            order: 0,
+            ..default()
+        },
+        Projection::Perspective(PerspectiveProjection {
+            fov: projection_state.last_perspective_fov, // 60 degrees unless restored from the config file
+            ..default()
+        }),
+        Transform::from_translation(CAMERA_START_POSITION).looking_at(Vec3::ZERO, Vec3::Y),
+        ViewportConstrainedCamera::default(),
+        ViewportConstrainedCameraState {
+            pitch: 0.0,
+            yaw: 0.0,
+            initialized: false,
+        },
+        RightCamera,
+        RenderLayers::from_layers(&[0, GIZMO_RENDER_LAYER]),
+    ));
+}
+
+fn update_camera_viewports(
+    window: Query<&Window>,
+    mut right_camera: Query<&mut Camera, With<RightCamera>>,
+    layout_state: Res<EguiLayoutState>,
+) {
+    let Ok(window) = window.single() else { return };
+    let physical_size = window.physical_size();
+    let scale_factor = window.scale_factor() as f32;
+    
+    // Use actual panel positions from Egui layout (in logical pixels, convert to physical)
+    let left_panel_end_physical = (layout_state.left_panel_end_x * scale_factor) as u32;
+    let top_bars_height_physical = (layout_state.top_bars_height * scale_factor) as u32;
+    let bottom_bar_height_physical = (layout_state.bottom_bar_height * scale_factor) as u32;
+    
+    // Calculate viewport width: extend to right edge if inspector is collapsed, otherwise stop at inspector
+    let viewport_right_edge = if layout_state.inspector_collapsed {
+        physical_size.x // Extend to right edge of window when inspector is hidden
+    } else {
+        (layout_state.right_panel_start_x * scale_factor) as u32 // Stop at inspector when visible
+    };
+    
+    // Calculate total available space: from left panel end to right edge (inspector or window edge)
+    // Height: from below top bars to above bottom bar
+    let total_viewport_width = viewport_right_edge.saturating_sub(left_panel_end_physical);
+    let viewport_height = physical_size.y.saturating_sub(top_bars_height_physical).saturating_sub(bottom_bar_height_physical);
+    
+    // Calculate camera viewport: if 3D viewer is hidden, set size to 0; otherwise calculate based on left panel
+    if let Ok(mut camera) = right_camera.single_mut() {
+        if !layout_state.d3_viewer_visible {
+            // Hide 3D viewer by setting viewport size to 0
+            camera.viewport = Some(Viewport {
+                physical_position: UVec2::new(0, 0),
+                physical_size: UVec2::new(0, 0),
+                ..default()
+            });
+        } else {
+            // Calculate camera viewport: if left half panel is visible, use right 50%, otherwise use full width
+            let (camera_viewport_x, camera_viewport_width) = if layout_state.left_half_panel_collapsed {
+                // Left panel is hidden: 3D world uses full width
+                (left_panel_end_physical, total_viewport_width)
+            } else {
+                // Left panel is visible: 3D world uses right half (50% width)
+                let half_width = total_viewport_width / 2;
+                (left_panel_end_physical + half_width, half_width)
+            };
+            
+            // Camera viewport (right half when left panel visible, full width when left panel hidden)
+            camera.viewport = Some(Viewport {
+                physical_position: UVec2::new(camera_viewport_x, top_bars_height_physical),
+                physical_size: UVec2::new(camera_viewport_width, viewport_height),
+                ..default()
+            });
+        }
+    }
+}
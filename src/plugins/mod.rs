@@ -2,3 +2,12 @@
 // Copyright (C) 2026 vecnode
 
 pub mod viewport_constrained_camera;
+pub mod particles;
+pub mod camera;
+pub mod scene;
+pub mod ui;
+
+pub use particles::ParticlePlugin;
+pub use camera::CameraPlugin;
+pub use scene::ScenePlugin;
+pub use ui::UiPlugin;
@@ -0,0 +1,183 @@
+// plugins/particles.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::*;
+use crate::setup::spawn_particles;
+
+/// Plugin registering particle state and the systems that create, select, transform and animate particles
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StartupParticleCount::from_env())
+            .init_resource::<ParticleSelectionState>()
+            .init_resource::<ParticlePositions>()
+            .init_resource::<Motion1State>()
+            .init_resource::<TrajectoryState>()
+            .init_resource::<SelectionBoxState>()
+            .init_resource::<MouseButtonState>()
+            .init_resource::<ParticleBoundsState>()
+            .init_resource::<ParticleGroupState>()
+            .init_resource::<ParticleCreationState>()
+            .init_resource::<SelectionTransformState>()
+            .init_resource::<ParticleRadius>()
+            .init_resource::<ParticleShapeState>()
+            .init_resource::<LayerState>()
+            .init_resource::<ParticleRng>()
+            .init_resource::<VelocityArrowState>()
+            .init_resource::<SelectionStyle>()
+            .init_resource::<TrajectoryStyle>()
+            .init_resource::<MaxParticles>()
+            .init_resource::<PendingSpawns>()
+            .init_resource::<SelectionStats>()
+            .init_resource::<SelectionBoundsState>()
+            .init_resource::<BrushSelectState>()
+            .init_resource::<SelectionGrowState>()
+            .init_resource::<PlaceAtCursorState>()
+            .init_resource::<WaveMotionState>()
+            .init_resource::<JitterMotionState>()
+            .init_resource::<AttractorState>()
+            .init_resource::<SpatialGrid>()
+            .init_resource::<FlockingState>()
+            .init_resource::<EmitterState>()
+            .init_resource::<ColorByState>()
+            .init_resource::<ProximityLinesState>()
+            .init_resource::<NeighborStats>()
+            .init_resource::<IoState>()
+            .init_resource::<PlaybackState>()
+            .add_message::<SelectionChanged>();
+        
+        app.add_systems(
+            Update,
+            (
+                track_mouse_button_state,
+                cleanup_mouse_button_state,
+                update_particle_bounds.after(handle_fit_bounds_to_particles),
+                update_particle_group_transform,
+                update_particle_radius,
+                handle_particle_selection,
+                update_selection_original_positions,
+                update_selection_transform,
+                animate_motion1_particles.after(update_selection_transform).run_if(live_simulation),
+                update_trajectory_visualization,
+                handle_right_mouse_button,
+                update_selection_box_visual,
+                process_selection_box,
+                handle_particle_creation,
+                handle_particle_removal,
+                handle_particle_reset.after(handle_particle_removal),
+                handle_particle_duplication,
+                update_particle_layers,
+                update_locked_particle_tint,
+                update_selection_bounding_box.after(update_selection_stats),
+            ),
+        );
+        // Flatten requests, kept apart from the continuous selection transform
+        app.add_systems(Update, handle_selection_flatten);
+        // Ctrl+C / Ctrl+V copy and paste of the selection
+        app.add_systems(Update, handle_copy_paste_shortcuts.before(handle_particle_duplication));
+        app.add_systems(
+            Update,
+            (
+                handle_fit_bounds_to_particles,
+                handle_trajectory_snapshots.after(update_trajectory_visualization),
+                update_selection_box_label.after(update_selection_box_visual),
+                handle_clear_selection,
+                handle_selection_grow_shrink,
+                handle_selection_scatter,
+                handle_selection_nudge,
+                handle_exact_position,
+                // Bakes the positions the selection transform produced this frame
+                handle_apply_selection_transform.after(update_selection_transform),
+                draw_velocity_arrows,
+                update_selection_outlines,
+                process_pending_spawns.after(handle_particle_creation),
+                draw_placement_preview,
+                handle_brush_selection,
+                draw_brush_cursor,
+                handle_place_at_cursor,
+                handle_spray_placement,
+                block_camera_rotation_while_spraying,
+                pulse_new_particles,
+                draw_place_at_cursor_preview,
+            ),
+        );
+        // Motion systems: they run after the selection transform so their positions are not overwritten,
+        // and pause while a recording is replayed
+        app.add_systems(
+            Update,
+            (
+                handle_wave_toggle,
+                animate_wave_particles
+                    .after(handle_wave_toggle)
+                    .after(update_selection_transform)
+                    .run_if(live_simulation),
+                animate_jitter_particles.after(update_selection_transform).run_if(live_simulation),
+                apply_attractor_force.after(update_selection_transform).run_if(live_simulation),
+                draw_attractor,
+                draw_motion1_center,
+                handle_solo_hover_motion,
+                update_motion_tint,
+                handle_flock_assignment,
+                animate_flocking
+                    .after(handle_flock_assignment)
+                    .after(update_selection_transform)
+                    .run_if(live_simulation),
+                update_selection_stats
+                    .after(update_selection_transform)
+                    .after(animate_motion1_particles)
+                    .after(animate_wave_particles)
+                    .after(animate_jitter_particles)
+                    .after(apply_attractor_force)
+                    .after(animate_flocking)
+                    .after(move_emitted_particles)
+                    .after(apply_playback_frame),
+                record_trajectory_trails.after(update_selection_stats),
+                draw_trajectory_trails.after(record_trajectory_trails),
+                draw_selection_centroid.after(update_selection_stats),
+                export_trajectory_csv.after(record_trajectory_trails),
+            ),
+        );
+        // Expiring and emitted particles
+        app.add_systems(
+            Update,
+            (
+                assign_particle_lifetime,
+                update_particle_lifetimes.after(assign_particle_lifetime).run_if(live_simulation),
+                emit_particles.run_if(live_simulation),
+                move_emitted_particles.after(update_selection_transform).run_if(live_simulation),
+                draw_emitter,
+                apply_color_by_field
+                    .after(update_particle_lifetimes)
+                    .after(pulse_new_particles)
+                    .after(update_locked_particle_tint),
+            ),
+        );
+        // Record and replay: frames are recorded after all motion, and replay overrides it
+        app.add_systems(
+            Update,
+            (
+                handle_playback_requests,
+                record_playback_frame.after(update_selection_stats),
+                apply_playback_frame
+                    .after(handle_playback_requests)
+                    .after(update_selection_transform)
+                    .after(move_emitted_particles),
+            ),
+        );
+        // Scene files
+        app.add_systems(Update, (export_scene_json, import_scene_json));
+        app.add_systems(Startup, (spawn_selection_box, spawn_particles));
+        // After all Update motion, so neighbor queries see this frame's positions
+        app.add_systems(
+            PostUpdate,
+            (
+                update_spatial_grid,
+                draw_proximity_lines.after(update_spatial_grid),
+                update_neighbor_stats.after(update_spatial_grid),
+            ),
+        );
+    }
+}
@@ -0,0 +1,39 @@
+// plugins/scene.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{GridState, GroundPlaneState, LightingState, RenderSettingsState, AxisLabelState, OrientationGizmoState};
+use crate::constants::WORLD_BACKGROUND_COLOR;
+use crate::setup::{setup_camera_and_lights, spawn_ground_plane};
+use crate::systems::{draw_axes, draw_grid, follow_selection_with_grid, update_selection_stats, update_axis_labels, draw_orientation_gizmo, update_ground_plane, apply_lighting_settings, apply_render_settings, apply_particle_glow};
+
+/// Plugin for the environment around the particles: lights, ground plane, grid, axes and render settings (including the particle glow)
+pub struct ScenePlugin;
+
+impl Plugin for ScenePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ClearColor(WORLD_BACKGROUND_COLOR))
+            .init_resource::<GridState>()
+            .init_resource::<GroundPlaneState>()
+            .init_resource::<LightingState>()
+            .init_resource::<RenderSettingsState>()
+            .init_resource::<AxisLabelState>()
+            .init_resource::<OrientationGizmoState>();
+        
+        app.add_systems(Startup, (setup_camera_and_lights, spawn_ground_plane));
+        app.add_systems(
+            Update,
+            (
+                draw_axes,
+                follow_selection_with_grid.after(update_selection_stats),
+                draw_grid.after(follow_selection_with_grid),
+                update_axis_labels,
+                draw_orientation_gizmo,
+                update_ground_plane.after(follow_selection_with_grid),
+                apply_lighting_settings,
+                apply_render_settings,
+                apply_particle_glow,
+            ),
+        );
+    }
+}
@@ -0,0 +1,47 @@
+// plugins/ui.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use bevy_egui::{EguiPlugin, EguiGlobalSettings, PrimaryEguiContext, EguiPrimaryContextPass};
+use crate::components::{EguiLayoutState, StreamsPanelState, MinimapState, Notifications};
+use crate::systems::{egui_controls_ui, handle_panel_shortcuts, expire_notifications, notify_particle_cap};
+
+/// Plugin for the egui control panels, drawn by their own camera on top of the 3D view
+pub struct UiPlugin;
+
+impl Plugin for UiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(EguiPlugin::default())
+            .insert_resource(EguiGlobalSettings {
+                auto_create_primary_context: false,
+                ..default()
+            })
+            .init_resource::<EguiLayoutState>()
+            .init_resource::<StreamsPanelState>()
+            .init_resource::<MinimapState>()
+            .init_resource::<Notifications>();
+        
+        app.add_systems(Startup, setup_egui_camera);
+        app.add_systems(Update, (handle_panel_shortcuts, expire_notifications, notify_particle_cap));
+        app.add_systems(EguiPrimaryContextPass, egui_controls_ui);
+    }
+}
+
+fn setup_egui_camera(
+    mut commands: Commands,
+    mut egui_global_settings: ResMut<EguiGlobalSettings>,
+) {
+    // Disable auto-create primary context
+    egui_global_settings.auto_create_primary_context = false;
+    
+    // Primary Egui context camera (renders UI on top)
+    commands.spawn((
+        PrimaryEguiContext,
+        Camera2d::default(),
+        Camera {
+            order: 10,
+            clear_color: ClearColorConfig::Custom(Color::NONE),
+            ..default()
+        },
+    ));
+}
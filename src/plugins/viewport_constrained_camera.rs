@@ -2,7 +2,7 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::RightCamera;
+use crate::components::{RightCamera, CameraViewChanged, CameraConstraintState};
 
 /// Marker component for viewport-constrained camera controller
 #[derive(Component)]
@@ -38,6 +38,7 @@ pub struct ViewportConstrainedCameraState {
 #[derive(Resource, Default)]
 pub struct CameraViewportCursorState {
     pub is_cursor_in_viewport: bool,
+    pub rotation_blocked: bool, // Set by tools that use left drags themselves (e.g. spray placement)
 }
 
 /// Updates cursor position state relative to camera viewport
@@ -117,10 +118,18 @@ pub fn handle_viewport_constrained_mouse_rotation(
     cursor_state: Res<CameraViewportCursorState>,
     windows: Query<&Window>,
     mut last_mouse_pos: Local<Option<Vec2>>,
+    camera_changed: Res<CameraViewChanged>,
 ) {
+    // A view change is easing the camera, input would fight it
+    if camera_changed.target.is_some() {
+        *last_mouse_pos = None;
+        return;
+    }
+    
     // Only process mouse rotation if left button is pressed AND cursor is in viewport
+    // (and no tool has claimed left drags)
     let left_button_pressed = mouse_button_input.pressed(MouseButton::Left);
-    if !left_button_pressed || !cursor_state.is_cursor_in_viewport {
+    if !left_button_pressed || !cursor_state.is_cursor_in_viewport || cursor_state.rotation_blocked {
         // Clear last position when button is released or cursor leaves viewport
         if !left_button_pressed {
             *last_mouse_pos = None;
@@ -172,6 +181,7 @@ pub fn handle_viewport_constrained_mouse_rotation(
 
 /// Handles keyboard movement (WASD, QE, Shift)
 /// Works regardless of cursor position (no viewport constraint for keyboard)
+/// Moved positions are clamped to the enabled ground / world bounds
 pub fn handle_viewport_constrained_keyboard_movement(
     mut cameras: Query<
         (&ViewportConstrainedCamera, &mut Transform),
@@ -179,7 +189,14 @@ pub fn handle_viewport_constrained_keyboard_movement(
     >,
     keyboard_input: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
+    camera_changed: Res<CameraViewChanged>,
+    constraints: Res<CameraConstraintState>,
 ) {
+    // A view change is easing the camera, input would fight it
+    if camera_changed.target.is_some() {
+        return;
+    }
+    
     let delta_time = time.delta_secs();
 
     for (camera, mut transform) in cameras.iter_mut() {
@@ -225,7 +242,7 @@ pub fn handle_viewport_constrained_keyboard_movement(
         // Normalize movement direction if moving in multiple directions
         if movement.length_squared() > 0.0 {
             movement = movement.normalize();
-            transform.translation += movement * speed * delta_time;
+            transform.translation = constraints.clamp(transform.translation + movement * speed * delta_time);
         }
     }
 }
@@ -4,87 +4,99 @@
 use bevy::prelude::*;
 use crate::constants::*;
 
-pub fn spawn_axes(
+pub fn setup_camera_and_lights(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut lighting_state: ResMut<crate::components::LightingState>,
 ) {
-    // X axis (red)
-    commands.spawn((
-        Mesh3d(meshes.add(Cylinder::new(AXIS_RADIUS, AXIS_LENGTH))),
-        MeshMaterial3d(materials.add(COLOR_RED)),
-        Transform::from_translation(Vec3::X * AXIS_LENGTH / 2.0)
-            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
-    ));
+    // Front light
+    let front_light = commands.spawn(DirectionalLight {
+        illuminance: FRONT_LIGHT_ILLUMINANCE,
+        shadows_enabled: true,
+        ..default()
+    }).id();
     
-    // Y axis (green)
-    commands.spawn((
-        Mesh3d(meshes.add(Cylinder::new(AXIS_RADIUS, AXIS_LENGTH))),
-        MeshMaterial3d(materials.add(COLOR_GREEN)),
-        Transform::from_translation(Vec3::Y * AXIS_LENGTH / 2.0),
-    ));
+    // Back light (from behind)
+    let back_light = commands.spawn((
+        DirectionalLight {
+            illuminance: BACK_LIGHT_ILLUMINANCE,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::PI)),
+    )).id();
     
-    // Z axis (blue)
+    // Store light entities so the lighting controls can update them
+    lighting_state.front_light = Some(front_light);
+    lighting_state.back_light = Some(back_light);
+}
+
+pub fn spawn_ground_plane(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Unit plane scaled to the grid size by update_ground_plane (hidden until toggled on)
+    // Not a Particle, so selection raycasts never hit it
     commands.spawn((
-        Mesh3d(meshes.add(Cylinder::new(AXIS_RADIUS, AXIS_LENGTH))),
-        MeshMaterial3d(materials.add(COLOR_BLUE)),
-        Transform::from_translation(Vec3::Z * AXIS_LENGTH / 2.0)
-            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        Mesh3d(meshes.add(Plane3d::default().mesh().size(1.0, 1.0))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: GROUND_PLANE_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        Transform::from_translation(Vec3::new(0.0, -0.001, 0.0)), // Slightly below the grid lines
+        Visibility::Hidden,
+        crate::components::GroundPlane,
     ));
 }
 
-pub fn spawn_grid(
+/// Spawn the stress-mode particles requested through WEB_PARTICLE_COUNT, randomly within the bounds
+pub fn spawn_particles(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    grid_state: Option<Res<crate::components::GridState>>,
+    mut particle_positions: ResMut<crate::components::ParticlePositions>,
+    mut particle_rng: ResMut<crate::components::ParticleRng>,
+    mut max_particles: ResMut<crate::components::MaxParticles>,
+    startup_count: Res<crate::components::StartupParticleCount>,
+    mut spawn_settings: crate::systems::SpawnSettings,
 ) {
-    // Use grid_state if available, otherwise use default values (10x10)
-    let size_x = grid_state.as_ref().map(|gs| gs.size_x).unwrap_or(10) as f32;
-    let size_z = grid_state.as_ref().map(|gs| gs.size_z).unwrap_or(10) as f32;
-    let half_size_x = size_x / 2.0;
-    let half_size_z = size_z / 2.0;
-    let num_lines_x = grid_state.as_ref().map(|gs| gs.size_x).unwrap_or(10) + 1;
-    let num_lines_z = grid_state.as_ref().map(|gs| gs.size_z).unwrap_or(10) + 1;
-    
-    // Create grid lines along X axis (parallel to Z) - these lines span the X direction
-    for i in 0..num_lines_z {
-        let z = -half_size_z + (i as f32 * GRID_SPACING);
-        commands.spawn((
-            Mesh3d(meshes.add(Cylinder::new(GRID_LINE_RADIUS, size_x))),
-            MeshMaterial3d(materials.add(GRID_COLOR)),
-            Transform::from_translation(Vec3::new(0.0, 0.0, z))
-                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
-            crate::components::GridLine,
-        ));
+    let count = startup_count.0;
+    if count == 0 {
+        return;
     }
     
-    // Create grid lines along Z axis (parallel to X) - these lines span the Z direction
-    for i in 0..num_lines_x {
-        let x = -half_size_x + (i as f32 * GRID_SPACING);
-        commands.spawn((
-            Mesh3d(meshes.add(Cylinder::new(GRID_LINE_RADIUS, size_z))),
-            MeshMaterial3d(materials.add(GRID_COLOR)),
-            Transform::from_translation(Vec3::new(x, 0.0, 0.0))
-                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
-            crate::components::GridLine,
-        ));
-    }
+    // Stress mode asks for this many particles, so the cap is raised to fit them
+    max_particles.limit = max_particles.limit.max(count);
+    spawn_initial_particles(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut particle_positions,
+        &mut particle_rng,
+        count,
+        &mut spawn_settings,
+    );
+    info!("Stress mode: spawned {} particles", count);
 }
 
-pub fn setup_camera_and_lights(mut commands: Commands) {
-    // Front light
-    commands.spawn(DirectionalLight {
-        illuminance: FRONT_LIGHT_ILLUMINANCE,
-        ..default()
-    });
-    
-    // Back light (from behind)
-    commands.spawn((
-        DirectionalLight {
-            illuminance: BACK_LIGHT_ILLUMINANCE,
-            ..default()
-        },
-        Transform::from_rotation(Quat::from_rotation_y(std::f32::consts::PI)),
-    ));
+/// Spawn the startup distribution (shared by startup and Reset Particles)
+pub fn spawn_initial_particles(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    particle_positions: &mut crate::components::ParticlePositions,
+    particle_rng: &mut crate::components::ParticleRng,
+    count: usize,
+    spawn_settings: &mut crate::systems::SpawnSettings,
+) {
+    let positions = crate::systems::random_positions(&mut particle_rng.rng, Some(&spawn_settings.bounds_state), count);
+    crate::systems::spawn_particles_at(
+        commands,
+        meshes,
+        materials,
+        particle_positions,
+        positions,
+        spawn_settings,
+    );
 }
@@ -3,31 +3,167 @@
 
 use bevy::prelude::*;
 use crate::components::CameraViewChanged;
+use crate::constants::{CAMERA_VIEW_SETTLE_DISTANCE, CAMERA_VIEW_SETTLE_ANGLE};
 use crate::plugins::viewport_constrained_camera::ViewportConstrainedCameraState;
 
+/// Camera pose that keeps the current viewing direction and fits a sphere in view
+/// Perspective: backs off until the sphere fits the narrower of the vertical and horizontal FOV,
+/// using the live projection (radius / sin(half angle), so the sphere itself and not just its
+/// center-plane cross-section fits). Orthographic: rescales the projection to fit the diameter and
+/// stays outside the sphere so it is not clipped by the near plane
+pub fn frame_sphere(transform: &Transform, projection: &mut Projection, center: Vec3, radius: f32) -> Transform {
+    let forward = transform.forward();
+    let distance = match projection {
+        Projection::Perspective(persp) => {
+            let half_vertical = persp.fov * 0.5;
+            let half_horizontal = (half_vertical.tan() * persp.aspect_ratio).atan();
+            radius / half_vertical.min(half_horizontal).sin()
+        }
+        Projection::Orthographic(ortho) => {
+            // Visible area at scale 1, from the area computed for the current scale
+            let base_size = ortho.area.size() / ortho.scale;
+            if base_size.min_element() > 0.0 {
+                ortho.scale = 2.0 * radius / base_size.min_element();
+            }
+            radius * 2.0
+        }
+        _ => radius * 2.0,
+    };
+    Transform::from_translation(center - forward * distance).with_rotation(transform.rotation)
+}
+
+// System to ease the camera toward the pose requested by a view button
+// Moves a frame-rate independent fraction of the remaining way each frame, then snaps to the
+// exact pose and flags the reset so the controller re-reads pitch/yaw
+pub fn smooth_camera_view_change(
+    time: Res<Time>,
+    mut camera_changed: ResMut<CameraViewChanged>,
+    mut camera_query: Query<&mut Transform>,
+) {
+    let (Some(target), Some(entity)) = (camera_changed.target, camera_changed.entity) else {
+        return;
+    };
+    let Ok(mut transform) = camera_query.get_mut(entity) else {
+        camera_changed.target = None;
+        return;
+    };
+    
+    let t = 1.0 - (-camera_changed.smoothing_rate * time.delta_secs()).exp();
+    transform.translation = transform.translation.lerp(target.translation, t);
+    transform.rotation = transform.rotation.slerp(target.rotation, t);
+    
+    if transform.translation.distance(target.translation) < CAMERA_VIEW_SETTLE_DISTANCE
+        && transform.rotation.angle_between(target.rotation) < CAMERA_VIEW_SETTLE_ANGLE
+    {
+        *transform = target;
+        camera_changed.target = None;
+        camera_changed.needs_reset = true;
+    }
+}
+
 // System to reset ViewportConstrainedCamera after camera view change
-// Resets the camera state so it reinitializes with the new transform's rotation
+// Marks the camera state uninitialized so it re-reads pitch/yaw from the new transform's rotation
 pub fn reset_viewport_constrained_camera_after_view_change(
-    mut commands: Commands,
     mut camera_changed: ResMut<CameraViewChanged>,
+    mut camera_query: Query<&mut ViewportConstrainedCameraState>,
 ) {
-    if camera_changed.needs_reset {
-        if let Some(entity) = camera_changed.entity {
-            // Remove ViewportConstrainedCameraState to force reinitialization
-            // The state will be re-initialized from the transform's rotation on next frame
-            commands.entity(entity).remove::<ViewportConstrainedCameraState>();
+    if camera_changed.needs_reset
+        && camera_changed.target.is_none()
+        && let Some(entity) = camera_changed.entity
+    {
+        // initialize_viewport_constrained_camera_state picks up the new rotation next frame
+        if let Ok(mut state) = camera_query.get_mut(entity) {
+            state.initialized = false;
+        }
+        
+        // Reset the flag
+        camera_changed.needs_reset = false;
+        camera_changed.entity = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::camera::CameraProjection;
+    
+    const CENTER: Vec3 = Vec3::new(1.0, 2.0, -3.0);
+    const RADIUS: f32 = 2.5;
+    
+    fn camera_transform() -> Transform {
+        Transform::from_xyz(4.0, 6.0, 8.0).looking_at(Vec3::ZERO, Vec3::Y)
+    }
+    
+    #[test]
+    fn frame_sphere_fits_the_perspective_frustum() {
+        // Wide and tall viewports: the narrower FOV limits the distance
+        for aspect_ratio in [16.0 / 9.0, 0.5] {
+            let persp = PerspectiveProjection { fov: 45.0_f32.to_radians(), aspect_ratio, ..default() };
+            let mut projection = Projection::Perspective(persp.clone());
+            let framed = frame_sphere(&camera_transform(), &mut projection, CENTER, RADIUS);
             
-            // Re-add ViewportConstrainedCameraState with initialized=false
-            // This will cause initialize_viewport_constrained_camera_state to read the new rotation
-            commands.entity(entity).insert(ViewportConstrainedCameraState {
-                pitch: 0.0,
-                yaw: 0.0,
-                initialized: false,
-            });
+            // Same viewing direction, aimed at the center
+            let to_center = CENTER - framed.translation;
+            assert!(to_center.normalize().abs_diff_eq(*camera_transform().forward(), 1e-5));
             
-            // Reset the flag
-            camera_changed.needs_reset = false;
-            camera_changed.entity = None;
+            // The sphere's angular radius fits within both half angles of the frustum
+            let angular_radius = (RADIUS / to_center.length()).asin();
+            let half_vertical = persp.fov * 0.5;
+            let half_horizontal = (half_vertical.tan() * aspect_ratio).atan();
+            assert!(angular_radius <= half_vertical + 1e-5, "{angular_radius} > {half_vertical}");
+            assert!(angular_radius <= half_horizontal + 1e-5, "{angular_radius} > {half_horizontal}");
+        }
+    }
+    
+    #[test]
+    fn frame_sphere_fits_the_orthographic_area() {
+        let mut ortho = OrthographicProjection::default_3d();
+        ortho.update(800.0, 600.0);
+        let mut projection = Projection::Orthographic(ortho);
+        let framed = frame_sphere(&camera_transform(), &mut projection, CENTER, RADIUS);
+        
+        let Projection::Orthographic(ref mut ortho) = projection else { unreachable!() };
+        ortho.update(800.0, 600.0);
+        let size = ortho.area.size();
+        assert!(size.min_element() >= 2.0 * RADIUS - 1e-4, "area {size} narrower than the diameter");
+        
+        // Camera stays outside the sphere, in front of it
+        let depth = (CENTER - framed.translation).dot(*framed.forward());
+        assert!(depth >= RADIUS, "camera at depth {depth} is inside the sphere");
+    }
+    
+    #[test]
+    fn view_change_reinitializes_the_constrained_camera() {
+        use bevy::ecs::system::RunSystemOnce;
+        use crate::components::RightCamera;
+        use crate::plugins::viewport_constrained_camera::{ViewportConstrainedCamera, initialize_viewport_constrained_camera_state};
+        
+        let mut app = crate::systems::test_harness::test_app();
+        let camera = app.world_mut().spawn((
+            Transform::default(),
+            ViewportConstrainedCamera::default(),
+            ViewportConstrainedCameraState { pitch: 0.0, yaw: 0.0, initialized: true },
+            RightCamera,
+        )).id();
+        
+        // A view button moved the camera and flagged the reset
+        let (yaw, pitch) = (0.7, -0.4);
+        app.world_mut().get_mut::<Transform>(camera).unwrap().rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.0);
+        {
+            let mut camera_changed = app.world_mut().resource_mut::<CameraViewChanged>();
+            camera_changed.entity = Some(camera);
+            camera_changed.needs_reset = true;
         }
+        
+        app.world_mut().run_system_once(reset_viewport_constrained_camera_after_view_change).unwrap();
+        assert!(!app.world().get::<ViewportConstrainedCameraState>(camera).unwrap().initialized);
+        let camera_changed = app.world().resource::<CameraViewChanged>();
+        assert!(!camera_changed.needs_reset && camera_changed.entity.is_none());
+        
+        // Next frame the controller re-reads pitch and yaw from the new rotation
+        app.world_mut().run_system_once(initialize_viewport_constrained_camera_state).unwrap();
+        let state = app.world().get::<ViewportConstrainedCameraState>(camera).unwrap();
+        assert!(state.initialized);
+        assert!((state.yaw - yaw).abs() < 1e-5 && (state.pitch - pitch).abs() < 1e-5);
     }
 }
@@ -0,0 +1,125 @@
+// systems/colormap.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{RenderSettingsState, Particle, ParticleBaseColor, Velocity, Locked, JustCreated, ColorByState, ColorByField, Colormap};
+use crate::constants::COLORMAP_BINS;
+use crate::systems::render_settings::glow_emissive;
+
+// Colormap control points (sRGB), sampled evenly from 0 to 1
+const VIRIDIS: [[f32; 3]; 11] = [
+    [0.267, 0.005, 0.329],
+    [0.283, 0.141, 0.458],
+    [0.254, 0.265, 0.530],
+    [0.207, 0.372, 0.553],
+    [0.164, 0.471, 0.558],
+    [0.128, 0.567, 0.551],
+    [0.135, 0.659, 0.518],
+    [0.267, 0.749, 0.441],
+    [0.478, 0.821, 0.319],
+    [0.741, 0.873, 0.150],
+    [0.993, 0.906, 0.144],
+];
+const TURBO: [[f32; 3]; 9] = [
+    [0.190, 0.072, 0.232],
+    [0.276, 0.421, 0.891],
+    [0.158, 0.736, 0.923],
+    [0.197, 0.949, 0.595],
+    [0.644, 0.990, 0.234],
+    [0.933, 0.812, 0.227],
+    [0.984, 0.493, 0.128],
+    [0.816, 0.185, 0.018],
+    [0.480, 0.016, 0.011],
+];
+const GRAYSCALE: [[f32; 3]; 2] = [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]];
+
+/// Color of the colormap at `t` (clamped to 0-1), linearly interpolated between control points
+pub fn colormap_color(colormap: Colormap, t: f32) -> Color {
+    let points: &[[f32; 3]] = match colormap {
+        Colormap::Viridis => &VIRIDIS,
+        Colormap::Turbo => &TURBO,
+        Colormap::Grayscale => &GRAYSCALE,
+    };
+    let scaled = t.clamp(0.0, 1.0) * (points.len() - 1) as f32;
+    let index = (scaled.floor() as usize).min(points.len() - 2);
+    let fraction = scaled - index as f32;
+    let [r0, g0, b0] = points[index];
+    let [r1, g1, b1] = points[index + 1];
+    Color::srgb(
+        r0 + (r1 - r0) * fraction,
+        g0 + (g1 - g0) * fraction,
+        b0 + (b1 - b0) * fraction,
+    )
+}
+
+/// System to color particles by their height or speed through the selected colormap
+/// Values are quantized into COLORMAP_BINS shared materials; particles showing another material
+/// (locked tint, creation pulse, lifetime fade) are left alone, and turning the mode off restores base colors
+pub fn apply_color_by_field(
+    mut color_by_state: ResMut<ColorByState>,
+    render_settings: Res<RenderSettingsState>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_query: Query<(&Transform, Option<&Velocity>, &mut MeshMaterial3d<StandardMaterial>, &ParticleBaseColor), (With<Particle>, Without<Locked>, Without<JustCreated>)>,
+    // Bin materials and the colormap they currently show
+    mut bins: Local<(Vec<Handle<StandardMaterial>>, Option<Colormap>)>,
+) {
+    let (bin_materials, bin_colormap) = &mut *bins;
+    
+    if color_by_state.field == ColorByField::None {
+        if !bin_materials.is_empty() {
+            for (_, _, mut material, base_color) in particle_query.iter_mut() {
+                if bin_materials.contains(&material.0) {
+                    material.0 = base_color.0.clone();
+                }
+            }
+            // Dropping the handles frees the bin materials
+            bin_materials.clear();
+            *bin_colormap = None;
+        }
+        return;
+    }
+    
+    // (Re)color the shared bins when the colormap or the particle glow changes
+    if bin_materials.is_empty() {
+        *bin_materials = (0..COLORMAP_BINS).map(|_| materials.add(StandardMaterial::default())).collect();
+    }
+    if *bin_colormap != Some(color_by_state.colormap) || render_settings.is_changed() {
+        for (index, handle) in bin_materials.iter().enumerate() {
+            if let Some(material) = materials.get_mut(handle) {
+                material.base_color = colormap_color(color_by_state.colormap, index as f32 / (COLORMAP_BINS - 1) as f32);
+                material.emissive = glow_emissive(material.base_color, render_settings.particle_glow);
+            }
+        }
+        *bin_colormap = Some(color_by_state.colormap);
+    }
+    
+    let field = color_by_state.field;
+    let value = |transform: &Transform, velocity: Option<&Velocity>| match field {
+        ColorByField::Speed => velocity.map(|velocity| velocity.0.length()).unwrap_or(0.0),
+        _ => transform.translation.y,
+    };
+    
+    if color_by_state.auto_range {
+        let (min, max) = particle_query.iter()
+            .map(|(transform, velocity, _, _)| value(transform, velocity))
+            .fold((f32::MAX, f32::MIN), |(min, max), v| (min.min(v), max.max(v)));
+        // Written only on change, so the manual fields show the last fitted range
+        if min <= max && (color_by_state.min != min || color_by_state.max != max) {
+            color_by_state.min = min;
+            color_by_state.max = max;
+        }
+    }
+    
+    let min = color_by_state.min;
+    let range = (color_by_state.max - min).max(f32::EPSILON);
+    for (transform, velocity, mut material, base_color) in particle_query.iter_mut() {
+        if material.0 != base_color.0 && !bin_materials.contains(&material.0) {
+            continue;
+        }
+        let t = ((value(transform, velocity) - min) / range).clamp(0.0, 1.0);
+        let bin = &bin_materials[(t * (COLORMAP_BINS - 1) as f32).round() as usize];
+        if material.0 != *bin {
+            material.0 = bin.clone();
+        }
+    }
+}
@@ -2,31 +2,247 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use bevy_egui::{egui, EguiContexts};
 use egui_plot::{Plot, PlotPoints, Line};
-use crate::components::{ParticleSelectionState, Motion1State, TrajectoryState, CameraViewChanged, CameraProjectionState, EguiLayoutState, GridState, ParticleBoundsState, ParticleGroupState, StreamsPanelState, ParticleCreationState, ParticlePlacementMode, InMotion, SelectionTransformState};
-use crate::constants::{CAMERA_FRONT_POSITION, CAMERA_TOP_POSITION, EGUI_TOP_BAR_HEIGHT, EGUI_SECOND_TOP_BAR_HEIGHT, EGUI_LEFT_PANEL_WIDTH};
+use crate::components::{ParticleSelectionState, Motion1State, TrajectoryState, CameraViewChanged, CameraProjectionState, EguiLayoutState, GridState, ParticleBoundsState, ParticleGroupState, StreamsPanelState, ParticleCreationState, ParticlePlacementMode, InMotion, SoloMotion, Locked, SelectionTransformState, ScalePivot, ParticleRadius, LayerState, OrbitPhase, VelocityArrowState, GroundPlaneState, LightingState, RenderSettingsState, TrajectoryStyle, MaxParticles, PendingSpawns, SelectionStats, WaveMotionState, JitterMotionState, AttractorState, AttractorFalloff, FlockingState, IoState, SelectionBoundsState, SelectionBoundsShape, BrushSelectState, PlaceAtCursorState, ParticleShape, ParticleShapeState, AxisLabelState, OrientationGizmoState, SelectionBoxState, EmitterState, ColorByState, ColorByField, Colormap, ProximityLinesState, NeighborStats, SelectionGrowState, CameraConstraintState, MinimapState, ParticlePositions, Notifications, PlaybackState, PlaybackMode};
+use crate::constants::{FRAME_SELECTION_PADDING, FRAME_SELECTION_MIN_RADIUS, NOTIFICATION_FADE_SECONDS, NOTIFICATION_WIDTH, MINIMAP_MARGIN, MINIMAP_DOT_SIZE, MINIMAP_FRUSTUM_LENGTH, COLOR_LEGEND_SIZE, COLOR_LEGEND_STEPS, HIDDEN_COUNT_INTERVAL_SECONDS, FOV_MIN_DEGREES, PARTICLE_GLOW_MAX, FOV_MAX_DEGREES, CAMERA_START_POSITION, EGUI_TOP_BAR_HEIGHT, EGUI_SECOND_TOP_BAR_HEIGHT, EGUI_LEFT_PANEL_WIDTH};
+
+/// Axis views: button label, camera direction from the origin and camera up vector
+/// Top and Bottom use a Z up vector so screen right stays +X, as in the front view
+/// (up = +Z on the top view mirrored it horizontally and with it box selection)
+const CAMERA_AXIS_VIEWS: [(&str, Vec3, Vec3); 6] = [
+    ("Front", Vec3::Z, Vec3::Y),
+    ("Back", Vec3::NEG_Z, Vec3::Y),
+    ("Left", Vec3::NEG_X, Vec3::Y),
+    ("Right", Vec3::X, Vec3::Y),
+    ("Top", Vec3::Y, Vec3::NEG_Z),
+    ("Bottom", Vec3::NEG_Y, Vec3::Z),
+];
+
+/// Move the camera to the `target` pose and flag the view change,
+/// so the orbit controller picks up the new pose instead of snapping back
+/// With smoothing enabled only the target is stored; smooth_camera_view_change eases toward it
+fn set_camera_view(
+    entity: Entity,
+    transform: &mut Transform,
+    global_transform: &mut GlobalTransform,
+    target: Transform,
+    camera_changed: &mut CameraViewChanged,
+) {
+    camera_changed.entity = Some(entity);
+    if camera_changed.smooth_views {
+        camera_changed.target = Some(target);
+        return;
+    }
+    *transform = target;
+    *global_transform = GlobalTransform::from(*transform);
+    camera_changed.target = None;
+    camera_changed.needs_reset = true;
+}
+
+/// System to toggle the panels from the keyboard: F1 Inspector, F2 Streams, F3 3D Viewer,
+/// F4 Middle-Left Panel
+/// Flips the same flags as the bar buttons, so both stay in sync; ignored while an egui
+/// widget has keyboard focus
+pub fn handle_panel_shortcuts(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut contexts: EguiContexts,
+    mut layout_state: ResMut<EguiLayoutState>,
+    mut streams_panel_state: ResMut<StreamsPanelState>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+    
+    if keyboard_input.just_pressed(KeyCode::F1) {
+        layout_state.inspector_collapsed = !layout_state.inspector_collapsed;
+    }
+    if keyboard_input.just_pressed(KeyCode::F2) {
+        streams_panel_state.is_visible = !streams_panel_state.is_visible;
+    }
+    if keyboard_input.just_pressed(KeyCode::F3) {
+        layout_state.d3_viewer_visible = !layout_state.d3_viewer_visible;
+    }
+    if keyboard_input.just_pressed(KeyCode::F4) {
+        layout_state.left_half_panel_collapsed = !layout_state.left_half_panel_collapsed;
+    }
+}
+
+/// Label text rebuilt only when the value it shows changes, instead of formatting every frame
+#[derive(Default)]
+pub struct CachedText<K> {
+    key: Option<K>,
+    text: String,
+}
+
+impl<K: PartialEq + Copy> CachedText<K> {
+    pub fn get(&mut self, key: K, format: impl FnOnce(K) -> String) -> &str {
+        if self.key != Some(key) {
+            self.text = format(key);
+            self.key = Some(key);
+        }
+        &self.text
+    }
+    
+    /// Text of the last key passed to `get`
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Cached texts of the labels that would otherwise be reformatted every frame
+#[derive(Default)]
+pub struct UiTextCache {
+    pub camera_position: CachedText<IVec3>, // Rounded to whole meters, so it changes at most once per meter
+    pub selected_count: CachedText<usize>,
+    pub particles_selected: CachedText<usize>,
+    pub motion1_label: CachedText<usize>,
+    pub recording_time: CachedText<i32>, // In tenths of a second, the precision shown
+    pub recorded_frames: CachedText<usize>,
+    pub wave_label: CachedText<usize>,
+    pub inspector_selected: CachedText<usize>,
+    pub centroid: CachedText<Vec3>,
+    pub size: CachedText<Vec3>,
+    pub total_count: CachedText<usize>,
+    pub in_motion_count: CachedText<usize>,
+    pub locked_count: CachedText<usize>,
+    pub hidden_count: CachedText<usize>,
+    pub hidden_counted_at: Option<f64>, // egui time of the last hidden recount
+    pub neighbor_mean: CachedText<f32>,
+    pub neighbor_range: CachedText<(f32, f32)>,
+    pub layer_counts: Vec<CachedText<usize>>, // By layer index; layers are only appended, so an index keeps its name
+    pub legend_title: CachedText<(ColorByField, Colormap)>,
+    pub legend_max: CachedText<(f32, &'static str)>,
+    pub legend_min: CachedText<(f32, &'static str)>,
+}
+
+/// Scene resources edited from the controls panel
+/// Grouped to keep egui_controls_ui within the system parameter limit
+#[derive(SystemParam)]
+pub struct SceneUiResources<'w, 's> {
+    pub particle_radius: ResMut<'w, ParticleRadius>,
+    pub layer_state: ResMut<'w, LayerState>,
+    pub velocity_arrow_state: ResMut<'w, VelocityArrowState>,
+    pub ground_plane_state: ResMut<'w, GroundPlaneState>,
+    pub lighting_state: ResMut<'w, LightingState>,
+    pub render_settings: ResMut<'w, RenderSettingsState>,
+    pub trajectory_style: ResMut<'w, TrajectoryStyle>,
+    pub max_particles: ResMut<'w, MaxParticles>,
+    pub pending_spawns: ResMut<'w, PendingSpawns>,
+    pub selection_stats: Res<'w, SelectionStats>,
+    pub wave_state: ResMut<'w, WaveMotionState>,
+    pub jitter_state: ResMut<'w, JitterMotionState>,
+    pub attractor_state: ResMut<'w, AttractorState>,
+    pub flocking_state: ResMut<'w, FlockingState>,
+    pub io_state: ResMut<'w, IoState>,
+    pub selection_bounds_state: ResMut<'w, SelectionBoundsState>,
+    pub brush_state: ResMut<'w, BrushSelectState>,
+    pub place_state: ResMut<'w, PlaceAtCursorState>,
+    pub shape_state: ResMut<'w, ParticleShapeState>,
+    pub axis_label_state: ResMut<'w, AxisLabelState>,
+    pub orientation_gizmo_state: ResMut<'w, OrientationGizmoState>,
+    pub selection_box_state: ResMut<'w, SelectionBoxState>,
+    pub emitter_state: ResMut<'w, EmitterState>,
+    pub color_by_state: ResMut<'w, ColorByState>,
+    pub proximity_state: ResMut<'w, ProximityLinesState>,
+    pub neighbor_stats: Res<'w, NeighborStats>,
+    pub grow_state: ResMut<'w, SelectionGrowState>,
+    pub camera_constraints: ResMut<'w, CameraConstraintState>,
+    pub minimap_state: ResMut<'w, MinimapState>,
+    pub particle_positions: Res<'w, ParticlePositions>,
+    pub notifications: Res<'w, Notifications>,
+    pub playback_state: ResMut<'w, PlaybackState>,
+    pub text_cache: Local<'s, UiTextCache>,
+}
+
+/// Paint the top-down minimap into `rect`: particles as dots (selected ones highlighted)
+/// and the camera as a triangle opening along its forward direction
+/// World X maps to the right and world Z downward, matching the Top view
+fn draw_minimap(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    minimap_state: &MinimapState,
+    particle_positions: &ParticlePositions,
+    selected: &std::collections::HashSet<Entity>,
+    camera: Option<(&Transform, &Projection)>,
+) {
+    painter.rect_filled(rect, 2.0, egui::Color32::from_rgba_unmultiplied(20, 20, 20, 220));
+    painter.rect_stroke(rect, 2.0, egui::Stroke::new(1.0, egui::Color32::GRAY), egui::StrokeKind::Inside);
+    
+    let extent = minimap_state.extent.max(0.001);
+    let scale = rect.width() * 0.5 / extent;
+    let to_screen = |position: Vec3| rect.center() + egui::vec2(position.x, position.z) * scale;
+    
+    // One dot per occupied cell keeps the cost bounded for large scenes
+    let mut cells = std::collections::HashMap::new();
+    for (entity, &position) in particle_positions.current_positions.iter() {
+        let point = to_screen(position);
+        if !rect.contains(point) {
+            continue;
+        }
+        let cell = ((point.x / MINIMAP_DOT_SIZE) as i32, (point.y / MINIMAP_DOT_SIZE) as i32);
+        let is_selected = cells.entry(cell).or_insert(false);
+        *is_selected |= selected.contains(entity);
+    }
+    for ((x, y), is_selected) in cells {
+        let color = if is_selected { egui::Color32::GREEN } else { egui::Color32::LIGHT_GRAY };
+        let min = egui::pos2(x as f32 * MINIMAP_DOT_SIZE, y as f32 * MINIMAP_DOT_SIZE);
+        painter.rect_filled(egui::Rect::from_min_size(min, egui::vec2(MINIMAP_DOT_SIZE, MINIMAP_DOT_SIZE)), 0.0, color);
+    }
+    
+    let Some((transform, projection)) = camera else { return };
+    let painter = painter.with_clip_rect(rect);
+    let apex = to_screen(transform.translation);
+    let forward = transform.forward();
+    let forward_xz = Vec2::new(forward.x, forward.z);
+    let camera_color = egui::Color32::from_rgb(255, 200, 0);
+    // Looking (almost) straight down or up has no horizontal direction to show
+    let Some(direction) = forward_xz.try_normalize() else {
+        painter.circle_stroke(apex, 4.0, egui::Stroke::new(1.5, camera_color));
+        return;
+    };
+    let half_angle = match projection {
+        Projection::Perspective(persp) => ((persp.fov * 0.5).tan() * persp.aspect_ratio).atan(),
+        _ => std::f32::consts::FRAC_PI_4,
+    };
+    let length = MINIMAP_FRUSTUM_LENGTH * rect.width() * 0.5;
+    let corner = |angle: f32| {
+        let edge = Vec2::from_angle(angle).rotate(direction) * length;
+        apex + egui::vec2(edge.x, edge.y)
+    };
+    painter.add(egui::Shape::convex_polygon(
+        vec![apex, corner(-half_angle), corner(half_angle)],
+        egui::Color32::from_rgba_unmultiplied(255, 200, 0, 40),
+        egui::Stroke::new(1.5, camera_color),
+    ));
+}
 
 pub fn egui_controls_ui(
     mut contexts: EguiContexts,
-    selection_state: Res<ParticleSelectionState>,
+    mut selection_state: ResMut<ParticleSelectionState>,
     mut motion1_state: ResMut<Motion1State>,
     mut trajectory_state: ResMut<TrajectoryState>,
     mut camera_changed: ResMut<CameraViewChanged>,
     mut projection_state: ResMut<CameraProjectionState>,
     mut layout_state: ResMut<EguiLayoutState>,
     mut grid_state: ResMut<GridState>,
-    mut _particle_bounds_state: ResMut<ParticleBoundsState>,
-    mut _particle_group_state: ResMut<ParticleGroupState>,
+    mut particle_bounds_state: ResMut<ParticleBoundsState>,
+    mut particle_group_state: ResMut<ParticleGroupState>,
     mut streams_panel_state: ResMut<StreamsPanelState>,
     mut creation_state: ResMut<ParticleCreationState>,
     mut selection_transform_state: ResMut<SelectionTransformState>,
+    mut scene: SceneUiResources,
     mut commands: Commands,
     mut queries: ParamSet<(
         Query<(Entity, &mut Transform, &mut GlobalTransform, &mut Projection), (With<bevy::prelude::Camera3d>, With<crate::plugins::viewport_constrained_camera::ViewportConstrainedCamera>, With<crate::components::RightCamera>)>,
         Query<&Transform, With<crate::components::Particle>>,
-        Query<Entity, (With<crate::components::Particle>, With<InMotion>)>,
+        Query<Entity, (With<crate::components::Particle>, With<InMotion>, Without<SoloMotion>)>,
         Query<Entity, With<crate::components::Particle>>,
+        Query<Entity, (With<crate::components::Particle>, With<Locked>)>,
+        Query<&Visibility, With<crate::components::Particle>>,
     )>,
 ) {
     if let Ok(ctx) = contexts.ctx_mut() {
@@ -42,7 +258,7 @@ pub fn egui_controls_ui(
                 ui.horizontal(|ui| {
                     ui.label("Web-Particle System");
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.label(format!("Selected: {}", selection_state.selected_particles.len()));
+                        ui.label(scene.text_cache.selected_count.get(selection_state.selected_particles.len(), |count| format!("Selected: {}", count)));
                     });
                 });
             });
@@ -67,6 +283,7 @@ pub fn egui_controls_ui(
                     .show(ui, |ui| {
                         ui.vertical(|ui| {
                             ui.heading("Controls");
+                            ui.label("Panels: F1 Inspector, F2 Streams, F3 3D Viewer, F4 Middle-Left Panel");
                             ui.separator();
                     
                     // Camera controls section
@@ -74,33 +291,105 @@ pub fn egui_controls_ui(
                     
                     // Camera position display (one line)
                     if let Ok((_, transform, _, _)) = queries.p0().single() {
-                        let pos = transform.translation;
-                        ui.label(format!("Camera Position: ({}, {}, {})", 
-                            pos.x.round() as i32, 
-                            pos.y.round() as i32, 
-                            pos.z.round() as i32));
+                        let pos = transform.translation.round().as_ivec3();
+                        ui.label(scene.text_cache.camera_position.get(pos, |pos| format!("Camera Position: ({}, {}, {})", pos.x, pos.y, pos.z)));
                     }
                     
-                    if ui.button("Camera Front").clicked() {
-                        if let Ok((entity, mut transform, mut global_transform, _)) = queries.p0().single_mut() {
-                            transform.translation = CAMERA_FRONT_POSITION;
-                            transform.look_at(Vec3::ZERO, Vec3::Y);
-                            *global_transform = GlobalTransform::from(*transform);
-                            camera_changed.needs_reset = true;
-                            camera_changed.entity = Some(entity);
+                    // Views along the principal axes, all looking at the origin
+                    let mut requested_view = None;
+                    ui.horizontal_wrapped(|ui| {
+                        for (label, direction, up) in CAMERA_AXIS_VIEWS {
+                            if ui.button(label).clicked() {
+                                requested_view = Some((direction * projection_state.axis_view_distance, up));
+                            }
                         }
+                    });
+                    let mut axis_view_distance = projection_state.axis_view_distance;
+                    if ui.add(egui::DragValue::new(&mut axis_view_distance)
+                        .range(1.0..=100.0)
+                        .speed(0.1)
+                        .prefix("View Distance: ")
+                        .suffix(" m")).changed() {
+                        projection_state.axis_view_distance = axis_view_distance;
                     }
                     
-                    if ui.button("Camera Top").clicked() {
-                        if let Ok((entity, mut transform, mut global_transform, _)) = queries.p0().single_mut() {
-                            transform.translation = CAMERA_TOP_POSITION;
-                            transform.look_at(Vec3::ZERO, Vec3::Z);
-                            *global_transform = GlobalTransform::from(*transform);
-                            camera_changed.needs_reset = true;
-                            camera_changed.entity = Some(entity);
-                        }
+                    // Back to the initial perspective the camera starts with
+                    if ui.button("Reset View").clicked() {
+                        requested_view = Some((CAMERA_START_POSITION, Vec3::Y));
+                    }
+                    
+                    if let Some((position, up)) = requested_view
+                        && let Ok((entity, mut transform, mut global_transform, _)) = queries.p0().single_mut() {
+                        let target = Transform::from_translation(position).looking_at(Vec3::ZERO, up);
+                        set_camera_view(entity, &mut transform, &mut global_transform, target, &mut camera_changed);
                     }
+                    
+                    // Fit the selection's bounding sphere in view, keeping the viewing direction
+                    if ui.add_enabled(scene.selection_stats.count > 0, egui::Button::new("Frame Selection")).clicked()
+                        && let Ok((entity, mut transform, mut global_transform, mut projection)) = queries.p0().single_mut() {
+                        let radius = (scene.selection_stats.radius * FRAME_SELECTION_PADDING).max(FRAME_SELECTION_MIN_RADIUS);
+                        let target = crate::systems::camera::frame_sphere(&transform, &mut projection, scene.selection_stats.centroid, radius);
+                        set_camera_view(entity, &mut transform, &mut global_transform, target, &mut camera_changed);
+                    }
+                    
+                    // Ease the view buttons instead of snapping
+                    ui.horizontal(|ui| {
+                        let mut smooth_views = camera_changed.smooth_views;
+                        if ui.checkbox(&mut smooth_views, "Smooth Views").changed() {
+                            camera_changed.smooth_views = smooth_views;
+                        }
+                        let mut smoothing_rate = camera_changed.smoothing_rate;
+                        if ui.add_enabled(smooth_views, egui::Slider::new(&mut smoothing_rate, 1.0..=30.0).text("Rate")).changed() {
+                            camera_changed.smoothing_rate = smoothing_rate;
+                        }
+                    });
 
+                    // Movement bounds (keyboard movement only, looking around is unaffected)
+                    ui.horizontal(|ui| {
+                        let mut clamp_above_ground = scene.camera_constraints.clamp_above_ground;
+                        if ui.checkbox(&mut clamp_above_ground, "Stay Above Ground").changed() {
+                            scene.camera_constraints.clamp_above_ground = clamp_above_ground;
+                        }
+                        let mut min_height = scene.camera_constraints.min_height;
+                        if ui.add_enabled(clamp_above_ground, egui::DragValue::new(&mut min_height)
+                            .range(-10.0..=10.0)
+                            .speed(0.05)
+                            .prefix("Min Y: ")
+                            .suffix(" m")).changed() {
+                            scene.camera_constraints.min_height = min_height;
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        let mut clamp_to_world = scene.camera_constraints.clamp_to_world;
+                        if ui.checkbox(&mut clamp_to_world, "World Bounds").changed() {
+                            scene.camera_constraints.clamp_to_world = clamp_to_world;
+                        }
+                        let mut half_extent = scene.camera_constraints.world_half_extent.x;
+                        if ui.add_enabled(clamp_to_world, egui::DragValue::new(&mut half_extent)
+                            .range(1.0..=1000.0)
+                            .speed(1.0)
+                            .prefix("Half Size: ")
+                            .suffix(" m")).changed() {
+                            scene.camera_constraints.world_half_extent = Vec3::splat(half_extent);
+                        }
+                    });
+                    
+                    // Top-down overview inset in the viewport corner
+                    ui.horizontal(|ui| {
+                        let mut minimap_visible = scene.minimap_state.is_visible;
+                        if ui.checkbox(&mut minimap_visible, "Minimap").changed() {
+                            scene.minimap_state.is_visible = minimap_visible;
+                        }
+                        let mut extent = scene.minimap_state.extent;
+                        if ui.add_enabled(minimap_visible, egui::DragValue::new(&mut extent)
+                            .range(1.0..=500.0)
+                            .speed(0.1)
+                            .prefix("Extent: ")
+                            .suffix(" m")).changed() {
+                            scene.minimap_state.extent = extent;
+                        }
+                    });
+                    
                     // Display projection mode label
                     ui.label("Perspective Camera");
                     
@@ -109,7 +398,9 @@ pub fn egui_controls_ui(
                     // Camera projection info and controls
                     if let Ok((_, _, _, mut projection)) = queries.p0().single_mut() {
                         // Update stored FOV if currently in perspective mode
-                        if let Projection::Perspective(ref persp) = *projection {
+                        // (only written on change, so change detection on the resource stays meaningful)
+                        if let Projection::Perspective(ref persp) = *projection
+                            && projection_state.last_perspective_fov != persp.fov {
                             projection_state.last_perspective_fov = persp.fov;
                         }
                         
@@ -120,7 +411,7 @@ pub fn egui_controls_ui(
                             
                             // Convert to degrees for user-friendly display
                             let mut fov_degrees = persp.fov.to_degrees();
-                            if ui.add(egui::Slider::new(&mut fov_degrees, 30.0..=120.0)
+                            if ui.add(egui::Slider::new(&mut fov_degrees, FOV_MIN_DEGREES..=FOV_MAX_DEGREES)
                                 .text("FOV (degrees)")
                                 .step_by(1.0)).changed() {
                                 persp.fov = fov_degrees.to_radians();
@@ -129,8 +420,64 @@ pub fn egui_controls_ui(
                         }
                     }
                     
+                    // Anti-aliasing (MSAA) sample count, starts at Bevy's default of 4x
+                    ui.horizontal(|ui| {
+                        ui.label("MSAA:");
+                        let mut msaa = scene.render_settings.msaa;
+                        let mut msaa_changed = false;
+                        msaa_changed |= ui.radio_value(&mut msaa, Msaa::Off, "Off").changed();
+                        msaa_changed |= ui.radio_value(&mut msaa, Msaa::Sample2, "2x").changed();
+                        msaa_changed |= ui.radio_value(&mut msaa, Msaa::Sample4, "4x").changed();
+                        if msaa_changed {
+                            scene.render_settings.msaa = msaa;
+                        }
+                    });
+                    
+                    // Particle glow, helps colored particles read against the dark background
+                    let mut particle_glow = scene.render_settings.particle_glow;
+                    if ui.add(egui::Slider::new(&mut particle_glow, 0.0..=PARTICLE_GLOW_MAX)
+                        .text("Particle Glow")
+                        .step_by(0.05)).changed() {
+                        scene.render_settings.particle_glow = particle_glow;
+                    }
+                    
+                    ui.separator();
+                    
+                    // Lighting section
+                    ui.label("Lighting");
+                    let mut front_illuminance = scene.lighting_state.front_illuminance;
+                    if ui.add(egui::Slider::new(&mut front_illuminance, 0.0..=10000.0)
+                        .text("Front (lux)")
+                        .step_by(50.0)).changed() {
+                        scene.lighting_state.front_illuminance = front_illuminance;
+                    }
+                    let mut back_illuminance = scene.lighting_state.back_illuminance;
+                    if ui.add(egui::Slider::new(&mut back_illuminance, 0.0..=10000.0)
+                        .text("Back (lux)")
+                        .step_by(50.0)).changed() {
+                        scene.lighting_state.back_illuminance = back_illuminance;
+                    }
+                    let mut light_yaw = scene.lighting_state.light_yaw;
+                    if ui.add(egui::Slider::new(&mut light_yaw, -180.0..=180.0)
+                        .text("Yaw (degrees)")
+                        .step_by(1.0)).changed() {
+                        scene.lighting_state.light_yaw = light_yaw;
+                    }
+                    let mut light_pitch = scene.lighting_state.light_pitch;
+                    if ui.add(egui::Slider::new(&mut light_pitch, -89.0..=89.0)
+                        .text("Pitch (degrees)")
+                        .step_by(1.0)).changed() {
+                        scene.lighting_state.light_pitch = light_pitch;
+                    }
+                    let mut ambient_brightness = scene.lighting_state.ambient_brightness;
+                    if ui.add(egui::Slider::new(&mut ambient_brightness, 0.0..=1000.0)
+                        .text("Ambient")
+                        .step_by(5.0)).changed() {
+                        scene.lighting_state.ambient_brightness = ambient_brightness;
+                    }
+                    
                     ui.separator();
-                    ui.label(format!("Particles Selected: {}", selection_state.selected_particles.len()));
+                    ui.label(scene.text_cache.particles_selected.get(selection_state.selected_particles.len(), |count| format!("Particles Selected: {}", count)));
 
                     // Particle Creation section
                     ui.separator();
@@ -154,10 +501,158 @@ pub fn egui_controls_ui(
                         }
                     });
                     
+                    // Streaming spreads large batches over several frames
+                    ui.horizontal(|ui| {
+                        let mut streaming = scene.pending_spawns.streaming;
+                        if ui.checkbox(&mut streaming, "Stream").changed() {
+                            scene.pending_spawns.streaming = streaming;
+                        }
+                        ui.label("Per Frame:");
+                        let mut chunk_size = scene.pending_spawns.chunk_size;
+                        if ui.add_enabled(streaming, egui::DragValue::new(&mut chunk_size)
+                            .range(1..=1000)
+                            .speed(1)).changed() {
+                            scene.pending_spawns.chunk_size = chunk_size;
+                        }
+                    });
+                    
+                    // Shape of new particles (existing particles keep theirs)
+                    ui.horizontal(|ui| {
+                        ui.label("Shape:");
+                        let mut shape = scene.shape_state.shape;
+                        egui::ComboBox::from_id_salt("particle_shape")
+                            .selected_text(shape.label())
+                            .show_ui(ui, |ui| {
+                                for option in ParticleShape::ALL {
+                                    ui.selectable_value(&mut shape, option, option.label());
+                                }
+                            });
+                        if shape != scene.shape_state.shape {
+                            scene.shape_state.shape = shape;
+                        }
+                    });
+                    
+                    // Color particles by a scalar field through a colormap
+                    ui.horizontal(|ui| {
+                        ui.label("Color By:");
+                        let mut field = scene.color_by_state.field;
+                        egui::ComboBox::from_id_salt("color_by_field")
+                            .selected_text(field.label())
+                            .show_ui(ui, |ui| {
+                                for option in ColorByField::ALL {
+                                    ui.selectable_value(&mut field, option, option.label());
+                                }
+                            });
+                        if field != scene.color_by_state.field {
+                            scene.color_by_state.field = field;
+                        }
+                        let mut colormap = scene.color_by_state.colormap;
+                        egui::ComboBox::from_id_salt("color_by_colormap")
+                            .selected_text(colormap.label())
+                            .show_ui(ui, |ui| {
+                                for option in Colormap::ALL {
+                                    ui.selectable_value(&mut colormap, option, option.label());
+                                }
+                            });
+                        if colormap != scene.color_by_state.colormap {
+                            scene.color_by_state.colormap = colormap;
+                        }
+                    });
+                    if scene.color_by_state.field != ColorByField::None {
+                        ui.horizontal(|ui| {
+                            let mut auto_range = scene.color_by_state.auto_range;
+                            if ui.checkbox(&mut auto_range, "Auto Range").changed() {
+                                scene.color_by_state.auto_range = auto_range;
+                            }
+                            let manual = !scene.color_by_state.auto_range;
+                            let mut range_min = scene.color_by_state.min;
+                            if ui.add_enabled(manual, egui::DragValue::new(&mut range_min).speed(0.05).prefix("Min: ")).changed() {
+                                scene.color_by_state.min = range_min;
+                            }
+                            let mut range_max = scene.color_by_state.max;
+                            if ui.add_enabled(manual, egui::DragValue::new(&mut range_max).speed(0.05).prefix("Max: ")).changed() {
+                                scene.color_by_state.max = range_max;
+                            }
+                        });
+                    }
+                    
+                    // Particle size (applies to existing and new particles)
+                    let mut radius = scene.particle_radius.0;
+                    if ui.add(egui::Slider::new(&mut radius, 0.005..=0.2)
+                        .text("Radius (m)")
+                        .step_by(0.005)).changed() {
+                        scene.particle_radius.0 = radius;
+                    }
+                    
+                    // Total particle cap
+                    ui.horizontal(|ui| {
+                        ui.label("Max Particles:");
+                        let mut limit = scene.max_particles.limit;
+                        if ui.add(egui::DragValue::new(&mut limit)
+                            .range(1..=100000)
+                            .speed(10)).changed() {
+                            scene.max_particles.limit = limit;
+                        }
+                    });
+                    
+                    // Place at cursor: left click spawns one particle on the placement plane
+                    ui.horizontal(|ui| {
+                        let mut place_active = scene.place_state.is_active;
+                        if ui.checkbox(&mut place_active, "Place at Cursor").changed() {
+                            scene.place_state.is_active = place_active;
+                            if place_active {
+                                scene.brush_state.is_active = false;
+                            }
+                        }
+                        let mut plane_height = scene.place_state.plane_height;
+                        if ui.add(egui::DragValue::new(&mut plane_height)
+                            .range(-10.0..=10.0)
+                            .speed(0.05)
+                            .prefix("Plane Y: ")
+                            .suffix(" m")).changed() {
+                            scene.place_state.plane_height = plane_height;
+                        }
+                    });
+                    // Spray: hold the left button and drag to place particles continuously
+                    ui.horizontal(|ui| {
+                        let mut spray = scene.place_state.spray;
+                        if ui.add_enabled(scene.place_state.is_active, egui::Checkbox::new(&mut spray, "Spray")).changed() {
+                            scene.place_state.spray = spray;
+                        }
+                        let mut spray_rate = scene.place_state.spray_rate;
+                        if ui.add_enabled(scene.place_state.is_active && spray, egui::DragValue::new(&mut spray_rate)
+                            .range(1.0..=500.0)
+                            .speed(1.0)
+                            .suffix(" /s")).changed() {
+                            scene.place_state.spray_rate = spray_rate;
+                        }
+                    });
+                    
+                    // Lifetime for new particles (they fade out and are removed when it runs out)
+                    ui.horizontal(|ui| {
+                        let mut lifetime_enabled = creation_state.lifetime_enabled;
+                        if ui.checkbox(&mut lifetime_enabled, "Lifetime").changed() {
+                            creation_state.lifetime_enabled = lifetime_enabled;
+                        }
+                        let mut lifetime_seconds = creation_state.lifetime_seconds;
+                        if ui.add_enabled(creation_state.lifetime_enabled, egui::DragValue::new(&mut lifetime_seconds)
+                            .range(0.1..=600.0)
+                            .speed(0.1)
+                            .suffix(" s")).changed() {
+                            creation_state.lifetime_seconds = lifetime_seconds;
+                        }
+                    });
+                    
                     // Create button
                     if ui.button("Create Particles").clicked() {
                         creation_state.create_requested = true;
                     }
+                    if !scene.pending_spawns.positions.is_empty() {
+                        ui.label(format!("Spawning: {} queued", scene.pending_spawns.positions.len()));
+                    }
+                    if scene.max_particles.clamped {
+                        ui.colored_label(egui::Color32::YELLOW, format!("Particle cap reached ({})", scene.max_particles.limit));
+                    }
                     
                     // Remove buttons
                     ui.horizontal(|ui| {
@@ -165,8 +660,100 @@ pub fn egui_controls_ui(
                         if ui.add_enabled(has_selected, egui::Button::new("Remove Selected")).clicked() {
                             creation_state.remove_selected_requested = true;
                         }
-                        if ui.button("Remove All").clicked() {
-                            creation_state.remove_all_requested = true;
+                        // Remove All needs a second click to confirm (there is no undo)
+                        if creation_state.remove_all_confirming {
+                            if ui.button("Confirm Remove All").clicked() {
+                                creation_state.remove_all_requested = true;
+                                creation_state.remove_all_confirming = false;
+                            }
+                            if ui.button("Cancel").clicked() {
+                                creation_state.remove_all_confirming = false;
+                            }
+                        } else if ui.button("Remove All").clicked() {
+                            creation_state.remove_all_confirming = true;
+                        }
+                    });
+                    
+                    // Reset Particles also needs a confirm click: it replaces every particle with the startup distribution
+                    ui.horizontal(|ui| {
+                        if creation_state.reset_confirming {
+                            if ui.button("Confirm Reset").clicked() {
+                                creation_state.reset_requested = true;
+                                creation_state.reset_confirming = false;
+                            }
+                            if ui.button("Cancel").clicked() {
+                                creation_state.reset_confirming = false;
+                            }
+                        } else if ui.button("Reset Particles")
+                            .on_hover_text("Remove all particles and respawn the startup distribution")
+                            .clicked() {
+                            creation_state.reset_confirming = true;
+                        }
+                    });
+                    
+                    if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new("Clear Selection")).clicked() {
+                        selection_state.clear_requested = true;
+                    }
+                    
+                    // Duplicate selected particles at an offset (clones become the selection)
+                    if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new("Duplicate Selection"))
+                        .on_hover_text("Ctrl+C / Ctrl+V copies and pastes the selection at the offset")
+                        .clicked() {
+                        creation_state.duplicate_requested = true;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Offset:");
+                        let mut offset = creation_state.duplicate_offset;
+                        let mut offset_changed = false;
+                        offset_changed |= ui.add(egui::DragValue::new(&mut offset.x).range(-10.0..=10.0).speed(0.05).prefix("X: ")).changed();
+                        offset_changed |= ui.add(egui::DragValue::new(&mut offset.y).range(-10.0..=10.0).speed(0.05).prefix("Y: ")).changed();
+                        offset_changed |= ui.add(egui::DragValue::new(&mut offset.z).range(-10.0..=10.0).speed(0.05).prefix("Z: ")).changed();
+                        if offset_changed {
+                            creation_state.duplicate_offset = offset;
+                        }
+                    });
+                    // Mirror copy: clones reflected across the plane through the selection centroid
+                    ui.horizontal(|ui| {
+                        let has_selected = !selection_state.selected_particles.is_empty();
+                        ui.label("Mirror Copy:");
+                        for (axis, label) in ["X", "Y", "Z"].into_iter().enumerate() {
+                            if ui.add_enabled(has_selected, egui::Button::new(label)).clicked() {
+                                creation_state.mirror_copy_requested = Some(axis);
+                            }
+                        }
+                    });
+                    
+                    // Lock/unlock selected particles (locked particles ignore motion and transforms)
+                    let locked_particles: std::collections::HashSet<Entity> = queries.p4().iter().collect();
+                    let all_selected_locked = !selection_state.selected_particles.is_empty()
+                        && selection_state.selected_particles.iter().all(|e| locked_particles.contains(e));
+                    let lock_label = if all_selected_locked { "Unlock Selected" } else { "Lock Selected" };
+                    if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new(lock_label)).clicked() {
+                        for entity in selection_state.selected_particles.iter() {
+                            if all_selected_locked {
+                                commands.entity(*entity).remove::<Locked>();
+                            } else {
+                                commands.entity(*entity).insert(Locked);
+                            }
+                        }
+                    }
+                    
+                    // Quick ad-hoc hide: hidden particles stay selected and keep moving,
+                    // but cannot be picked or box-selected
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new("Hide Selected")).clicked() {
+                            for entity in selection_state.selected_particles.iter() {
+                                commands.entity(*entity).insert(Visibility::Hidden);
+                            }
+                        }
+                        if ui.button("Show All").clicked() {
+                            for entity in queries.p3().iter() {
+                                commands.entity(entity).insert(Visibility::Visible);
+                            }
+                            // Layer toggles would otherwise disagree with what is shown
+                            for layer in scene.layer_state.layers.iter_mut() {
+                                layer.visible = true;
+                            }
                         }
                     });
                     
@@ -175,6 +762,11 @@ pub fn egui_controls_ui(
                         ui.separator();
                         ui.label("Ball Parameters");
                         
+                        // Move the spawn center onto the current selection
+                        if ui.add_enabled(scene.selection_stats.count > 0, egui::Button::new("Center on Selection")).clicked() {
+                            creation_state.ball_center = scene.selection_stats.centroid;
+                        }
+                        
                         ui.horizontal(|ui| {
                             ui.label("Center X:");
                             if ui.add(egui::DragValue::new(&mut creation_state.ball_center.x)
@@ -210,6 +802,11 @@ pub fn egui_controls_ui(
                         ui.separator();
                         ui.label("Cube Parameters");
                         
+                        // Move the spawn center onto the current selection
+                        if ui.add_enabled(scene.selection_stats.count > 0, egui::Button::new("Center on Selection")).clicked() {
+                            creation_state.cube_center = scene.selection_stats.centroid;
+                        }
+                        
                         ui.horizontal(|ui| {
                             ui.label("Center X:");
                             if ui.add(egui::DragValue::new(&mut creation_state.cube_center.x)
@@ -279,7 +876,97 @@ pub fn egui_controls_ui(
                         grid_state.size_z = size_z;
                     }
                     
+                    // Keep the grid centered under the selection; turning it off returns the grid to the origin
+                    let mut follow_selection = grid_state.follow_selection;
+                    if ui.checkbox(&mut follow_selection, "Center Grid Under Selection").changed() {
+                        grid_state.follow_selection = follow_selection;
+                        if !follow_selection {
+                            grid_state.offset = Vec2::ZERO;
+                        }
+                    }
+                    
+                    // Grid center offset (only moves the drawn grid and ground plane)
+                    ui.add_enabled_ui(!grid_state.follow_selection, |ui| ui.horizontal(|ui| {
+                        ui.label("Offset:");
+                        let mut offset = grid_state.offset;
+                        let mut offset_changed = false;
+                        offset_changed |= ui.add(egui::DragValue::new(&mut offset.x).range(-100.0..=100.0).speed(0.1).prefix("X: ")).changed();
+                        offset_changed |= ui.add(egui::DragValue::new(&mut offset.y).range(-100.0..=100.0).speed(0.1).prefix("Z: ")).changed();
+                        if offset_changed {
+                            grid_state.offset = offset;
+                        }
+                    }));
+                    
+                    // Ground plane under the grid (catches light shadows)
+                    let mut show_ground = scene.ground_plane_state.is_visible;
+                    if ui.checkbox(&mut show_ground, "Show Ground Plane").changed() {
+                        scene.ground_plane_state.is_visible = show_ground;
+                    }
+                    
+                    let mut show_axis_labels = scene.axis_label_state.is_visible;
+                    if ui.checkbox(&mut show_axis_labels, "Show Axis Labels").changed() {
+                        scene.axis_label_state.is_visible = show_axis_labels;
+                    }
+                    let mut show_orientation = scene.orientation_gizmo_state.is_visible;
+                    if ui.checkbox(&mut show_orientation, "Show Orientation Gizmo").changed() {
+                        scene.orientation_gizmo_state.is_visible = show_orientation;
+                    }
+                    
+                    
+                    
+                    // Particle distribution region (random placement, scatter and bounded motion)
+                    ui.label("Particle Bounds (meters)");
+                    for (axis, label) in ["X", "Y", "Z"].into_iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{}:", label));
+                            let mut min = particle_bounds_state.min[axis];
+                            let max_limit = particle_bounds_state.max[axis];
+                            if ui.add(egui::DragValue::new(&mut min)
+                                .range(-100.0..=max_limit)
+                                .speed(0.1)
+                                .prefix("min ")).changed() {
+                                particle_bounds_state.min[axis] = min;
+                            }
+                            let mut max = particle_bounds_state.max[axis];
+                            let min_limit = particle_bounds_state.min[axis];
+                            if ui.add(egui::DragValue::new(&mut max)
+                                .range(min_limit..=100.0)
+                                .speed(0.1)
+                                .prefix("max ")).changed() {
+                                particle_bounds_state.max[axis] = max;
+                            }
+                        });
+                    }
+                    ui.horizontal(|ui| {
+                        if ui.button("Fit Bounds to Particles").clicked() {
+                            particle_bounds_state.fit_requested = true;
+                        }
+                        let mut fit_grid = particle_bounds_state.fit_grid;
+                        if ui.checkbox(&mut fit_grid, "Fit Grid").changed() {
+                            particle_bounds_state.fit_grid = fit_grid;
+                        }
+                    });
                     
+                    // Scale of the whole particle cloud around the origin (before the group offset)
+                    ui.horizontal(|ui| {
+                        ui.label("Group Scale");
+                        let mut uniform = particle_group_state.uniform_scale;
+                        if ui.checkbox(&mut uniform, "Uniform").changed() {
+                            particle_group_state.uniform_scale = uniform;
+                        }
+                    });
+                    for (axis, label) in ["Scale X", "Scale Y", "Scale Z"].into_iter().enumerate() {
+                        let mut scale = particle_group_state.scale[axis];
+                        if ui.add(egui::Slider::new(&mut scale, 0.1..=5.0)
+                            .text(label)
+                            .step_by(0.1)).changed() {
+                            if particle_group_state.uniform_scale {
+                                particle_group_state.scale = Vec3::splat(scale);
+                            } else {
+                                particle_group_state.scale[axis] = scale;
+                            }
+                        }
+                    }
                     
                     // Selection position offset controls section
                     ui.label("Selection Distribution Area (meters)");
@@ -318,6 +1005,34 @@ pub fn egui_controls_ui(
                     // Selection scale controls section
                     ui.label("Selection Transform");
                     
+                    // Point the scale is applied about
+                    ui.horizontal(|ui| {
+                        ui.label("Scale Pivot:");
+                        let mut pivot = selection_transform_state.scale_pivot;
+                        egui::ComboBox::from_id_salt("selection_scale_pivot")
+                            .selected_text(pivot.label())
+                            .show_ui(ui, |ui| {
+                                for option in ScalePivot::ALL {
+                                    ui.selectable_value(&mut pivot, option, option.label());
+                                }
+                            });
+                        if pivot != selection_transform_state.scale_pivot {
+                            selection_transform_state.scale_pivot = pivot;
+                        }
+                    });
+                    if selection_transform_state.scale_pivot == ScalePivot::Custom {
+                        ui.horizontal(|ui| {
+                            let mut pivot = selection_transform_state.custom_pivot;
+                            let mut pivot_changed = false;
+                            pivot_changed |= ui.add(egui::DragValue::new(&mut pivot.x).speed(0.05).prefix("X: ")).changed();
+                            pivot_changed |= ui.add(egui::DragValue::new(&mut pivot.y).speed(0.05).prefix("Y: ")).changed();
+                            pivot_changed |= ui.add(egui::DragValue::new(&mut pivot.z).speed(0.05).prefix("Z: ")).changed();
+                            if pivot_changed {
+                                selection_transform_state.custom_pivot = pivot;
+                            }
+                        });
+                    }
+                    
                     // Scale X (normal distribution)
                     let mut scale_x = selection_transform_state.scale.x;
                     if ui.add(egui::Slider::new(&mut scale_x, 0.1..=5.0)
@@ -342,6 +1057,96 @@ pub fn egui_controls_ui(
                         selection_transform_state.scale.z = scale_z;
                     }
                     
+                    // Flatten selection onto a plane (coordinate set to the selection mean)
+                    ui.horizontal(|ui| {
+                        let can_flatten = selection_state.selected_particles.len() >= 2;
+                        ui.label("Flatten:");
+                        for (axis, label) in ["X", "Y", "Z"].into_iter().enumerate() {
+                            if ui.add_enabled(can_flatten, egui::Button::new(label)).clicked() {
+                                selection_transform_state.flatten_requested = Some(axis);
+                            }
+                        }
+                    });
+                    
+                    // Selection bounds visualization
+                    ui.horizontal(|ui| {
+                        ui.label("Bounds:");
+                        let mut shape = scene.selection_bounds_state.shape;
+                        if ui.radio_value(&mut shape, SelectionBoundsShape::Box, "Box").changed()
+                            | ui.radio_value(&mut shape, SelectionBoundsShape::Sphere, "Sphere").changed() {
+                            scene.selection_bounds_state.shape = shape;
+                        }
+                    });
+                    let mut show_centroid = scene.selection_bounds_state.show_centroid;
+                    if ui.checkbox(&mut show_centroid, "Show Centroid").changed() {
+                        scene.selection_bounds_state.show_centroid = show_centroid;
+                    }
+                    
+                    // Grow/shrink the selection by neighbor distance (flood-select clusters)
+                    ui.horizontal(|ui| {
+                        let has_selected = !selection_state.selected_particles.is_empty();
+                        if ui.add_enabled(has_selected, egui::Button::new("Grow Selection")).clicked() {
+                            scene.grow_state.grow_requested = true;
+                        }
+                        if ui.add_enabled(has_selected, egui::Button::new("Shrink Selection")).clicked() {
+                            scene.grow_state.shrink_requested = true;
+                        }
+                        let mut grow_radius = scene.grow_state.radius;
+                        if ui.add(egui::DragValue::new(&mut grow_radius)
+                            .range(0.01..=10.0)
+                            .speed(0.01)
+                            .prefix("Radius: ")
+                            .suffix(" m")).changed() {
+                            scene.grow_state.radius = grow_radius;
+                        }
+                    });
+                    
+                    // Right-drag box label lists how many particles are inside (projects every particle per frame)
+                    let mut show_count_preview = scene.selection_box_state.show_count_preview;
+                    if ui.checkbox(&mut show_count_preview, "Box Select Count Preview").changed() {
+                        scene.selection_box_state.show_count_preview = show_count_preview;
+                    }
+                    
+                    // Brush select: left click selects every particle within the radius of the ground point
+                    ui.horizontal(|ui| {
+                        let mut brush_active = scene.brush_state.is_active;
+                        if ui.checkbox(&mut brush_active, "Brush Select").changed() {
+                            scene.brush_state.is_active = brush_active;
+                            // Both tools take over left clicks, only one can be active
+                            if brush_active {
+                                scene.place_state.is_active = false;
+                            }
+                        }
+                        let mut brush_radius = scene.brush_state.radius;
+                        if ui.add(egui::DragValue::new(&mut brush_radius)
+                            .range(0.05..=10.0)
+                            .speed(0.01)
+                            .prefix("Radius: ")
+                            .suffix(" m")).changed() {
+                            scene.brush_state.radius = brush_radius;
+                        }
+                    });
+                    
+                    // Arrow keys / PageUp / PageDown nudge the selection by this step (Shift: larger)
+                    let mut nudge_step = selection_transform_state.nudge_step;
+                    if ui.add(egui::DragValue::new(&mut nudge_step)
+                        .range(0.001..=10.0)
+                        .speed(0.01)
+                        .prefix("Nudge Step: ")
+                        .suffix(" m")).changed() {
+                        selection_transform_state.nudge_step = nudge_step;
+                    }
+                    
+                    // Make the current offset/scale permanent and reset the sliders
+                    if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new("Apply Transform")).clicked() {
+                        selection_transform_state.apply_requested = true;
+                    }
+                    
+                    // Re-randomize the selected particles within the particle bounds
+                    if ui.add_enabled(!selection_state.selected_particles.is_empty(), egui::Button::new("Scatter Selection")).clicked() {
+                        selection_transform_state.scatter_requested = true;
+                    }
+                    
                    
                     ui.separator();
                     
@@ -349,58 +1154,526 @@ pub fn egui_controls_ui(
                     // Check if any particles are in motion to determine button label
                     let particles_with_motion: std::collections::HashSet<Entity> = queries.p2().iter().collect();
                     let particles_in_motion = particles_with_motion.len();
-                    let motion1_label = if particles_in_motion > 0 { 
-                        format!("Motion 1 (Active: {})", particles_in_motion) 
-                    } else { 
-                        "Motion 1".to_string() 
-                    };
+                    let motion1_label = scene.text_cache.motion1_label.get(particles_in_motion, |count| {
+                        if count > 0 { 
+                            format!("Motion 1 (Active: {})", count) 
+                        } else { 
+                            "Motion 1".to_string() 
+                        }
+                    });
                     if ui.button(motion1_label).clicked() {
-                        // If particles are selected, toggle motion only for selected particles
-                        // If no particles are selected, toggle motion for all particles
-                        if !selection_state.selected_particles.is_empty() {
-                            // Toggle InMotion component for selected particles only
-                            for entity in selection_state.selected_particles.iter() {
-                                if particles_with_motion.contains(entity) {
-                                    // Remove InMotion component (stop motion)
-                                    commands.entity(*entity).remove::<InMotion>();
-                                } else {
-                                    // Add InMotion component (start motion)
-                                    commands.entity(*entity).insert(InMotion);
-                                }
+                        // Selected particles toggle individually; without a selection all particles toggle together
+                        // (stable order, so staggered phases follow the entity order)
+                        let mut all_particles: Vec<Entity> = queries.p3().iter().collect();
+                        all_particles.sort_unstable();
+                        let (to_add, to_remove, is_active) = crate::systems::particles::compute_motion_toggle(
+                            &selection_state.sorted(),
+                            &particles_with_motion,
+                            &all_particles,
+                        );
+                        for entity in to_remove {
+                            commands.entity(entity).remove::<(InMotion, OrbitPhase)>();
+                        }
+                        // Particles being started share the phase spread, and their motion is now persistent even if hovered
+                        let starting = to_add.len();
+                        for (start_index, entity) in to_add.into_iter().enumerate() {
+                            commands.entity(entity).insert(InMotion).remove::<SoloMotion>();
+                            if motion1_state.stagger_phases {
+                                commands.entity(entity).insert(OrbitPhase(motion1_state.phase_for(start_index, starting)));
+                            }
+                        }
+                        motion1_state.is_active = is_active;
+                    }
+                    
+                    // Orbit just the particle under the cursor (does not change the persistent motion)
+                    let mut solo_on_hover = motion1_state.solo_on_hover;
+                    if ui.checkbox(&mut solo_on_hover, "Solo Motion On Hover").changed() {
+                        motion1_state.solo_on_hover = solo_on_hover;
+                    }
+                    
+                    // Emissive tint on particles in Motion1
+                    let mut tint_in_motion = motion1_state.tint_in_motion;
+                    if ui.checkbox(&mut tint_in_motion, "Tint Particles In Motion").changed() {
+                        motion1_state.tint_in_motion = tint_in_motion;
+                    }
+                    
+                    // Staggered starting phases (applied to particles when their motion starts)
+                    ui.horizontal(|ui| {
+                        let mut stagger_phases = motion1_state.stagger_phases;
+                        if ui.checkbox(&mut stagger_phases, "Stagger Phases").changed() {
+                            motion1_state.stagger_phases = stagger_phases;
+                        }
+                        let mut phase_spread = motion1_state.phase_spread;
+                        if ui.add_enabled(stagger_phases, egui::Slider::new(&mut phase_spread, 0.0..=std::f32::consts::TAU).text("Spread (rad)")).changed() {
+                            motion1_state.phase_spread = phase_spread;
+                        }
+                    });
+                    
+                    // Motion 1 orbit axis (Y = flat orbit in the XZ plane)
+                    ui.label("Orbit Axis");
+                    ui.horizontal(|ui| {
+                        let mut axis = motion1_state.rotation_axis;
+                        let mut axis_changed = false;
+                        axis_changed |= ui.add(egui::DragValue::new(&mut axis.x)
+                            .range(-1.0..=1.0)
+                            .speed(0.05)
+                            .prefix("X: ")).changed();
+                        axis_changed |= ui.add(egui::DragValue::new(&mut axis.y)
+                            .range(-1.0..=1.0)
+                            .speed(0.05)
+                            .prefix("Y: ")).changed();
+                        axis_changed |= ui.add(egui::DragValue::new(&mut axis.z)
+                            .range(-1.0..=1.0)
+                            .speed(0.05)
+                            .prefix("Z: ")).changed();
+                        if axis_changed {
+                            motion1_state.rotation_axis = axis;
+                        }
+                    });
+                    
+                    // Motion 1 pivot (follows the group offset by default)
+                    let mut use_group_offset = motion1_state.use_group_offset;
+                    if ui.checkbox(&mut use_group_offset, "Orbit Around Group Offset").changed() {
+                        motion1_state.use_group_offset = use_group_offset;
+                    }
+                    ui.add_enabled_ui(!motion1_state.use_group_offset, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("Center:");
+                            let mut center = motion1_state.rotation_center;
+                            let mut center_changed = false;
+                            center_changed |= ui.add(egui::DragValue::new(&mut center.x).speed(0.05).prefix("X: ")).changed();
+                            center_changed |= ui.add(egui::DragValue::new(&mut center.y).speed(0.05).prefix("Y: ")).changed();
+                            center_changed |= ui.add(egui::DragValue::new(&mut center.z).speed(0.05).prefix("Z: ")).changed();
+                            if center_changed {
+                                motion1_state.rotation_center = center;
                             }
-                            // Update is_active: true if any particles (selected or not) will be in motion after toggle
-                            let selected_in_motion_before = selection_state.selected_particles.iter()
-                                .filter(|e| particles_with_motion.contains(e))
-                                .count();
-                            let non_selected_in_motion = particles_with_motion.len() - selected_in_motion_before;
-                            let selected_in_motion_after = selection_state.selected_particles.len() - selected_in_motion_before;
-                            motion1_state.is_active = (non_selected_in_motion + selected_in_motion_after) > 0;
+                        });
+                    });
+                    
+                    // Show Trajectory button
+                    let trajectory_label = if trajectory_state.is_visible { "Hide Trajectory" } else { "Show Trajectory" };
+                    if ui.button(trajectory_label).clicked() {
+                        trajectory_state.is_visible = !trajectory_state.is_visible;
+                    }
+                    // Lighter alternative: a short fading trail behind selected particles in Motion1
+                    let mut motion_trails = trajectory_state.motion_trails;
+                    if ui.checkbox(&mut motion_trails, "Short Motion Trails").changed() {
+                        trajectory_state.motion_trails = motion_trails;
+                    }
+                    // Frozen copies of the current circles/trails (kept after hiding or stopping)
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(trajectory_state.is_visible, egui::Button::new("Snapshot Trajectory")).clicked() {
+                            trajectory_state.snapshot_requested = true;
+                        }
+                        if ui.button("Clear Snapshots").clicked() {
+                            trajectory_state.clear_snapshots_requested = true;
+                        }
+                    });
+                    // Trail recording (length and sampling rate)
+                    ui.horizontal(|ui| {
+                        ui.label("Trail Points:");
+                        let mut max_points = trajectory_state.max_points;
+                        if ui.add(egui::DragValue::new(&mut max_points)
+                            .range(2..=10000)
+                            .speed(10)).changed() {
+                            trajectory_state.max_points = max_points;
+                        }
+                        ui.label("Every N Frames:");
+                        let mut sample_interval = trajectory_state.sample_interval;
+                        if ui.add(egui::DragValue::new(&mut sample_interval)
+                            .range(1..=120)
+                            .speed(1)).changed() {
+                            trajectory_state.sample_interval = sample_interval;
+                        }
+                    });
+                    // Export the selected particles' recorded trails (particle,t,x,y,z)
+                    ui.horizontal(|ui| {
+                        let mut export_path = scene.io_state.trail_export_path.clone();
+                        if ui.text_edit_singleline(&mut export_path).changed() {
+                            scene.io_state.trail_export_path = export_path;
+                        }
+                        let has_selected = !selection_state.selected_particles.is_empty();
+                        if ui.add_enabled(has_selected, egui::Button::new("Export Trail CSV")).clicked() {
+                            scene.io_state.export_trails_requested = true;
+                        }
+                    });
+                    // Save / load every particle as a JSON array of {x, y, z, selected, color}
+                    ui.horizontal(|ui| {
+                        let mut scene_path = scene.io_state.scene_path.clone();
+                        if ui.text_edit_singleline(&mut scene_path).changed() {
+                            scene.io_state.scene_path = scene_path;
+                        }
+                        if ui.button("Export JSON").clicked() {
+                            scene.io_state.export_scene_requested = true;
+                        }
+                        if ui.button("Import JSON").clicked() {
+                            scene.io_state.import_scene_requested = true;
+                        }
+                    });
+                    if !scene.io_state.status.is_empty() {
+                        ui.label(&scene.io_state.status);
+                    }
+                    let mut trajectory_thickness = scene.trajectory_style.thickness;
+                    if ui.add(egui::Slider::new(&mut trajectory_thickness, 0.002..=0.1)
+                        .text("Thickness")
+                        .step_by(0.002)).changed() {
+                        scene.trajectory_style.thickness = trajectory_thickness;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Color:");
+                        let mut trajectory_color = scene.trajectory_style.color.to_srgba().to_f32_array();
+                        if ui.color_edit_button_rgba_unmultiplied(&mut trajectory_color).changed() {
+                            scene.trajectory_style.color = Color::srgba(trajectory_color[0], trajectory_color[1], trajectory_color[2], trajectory_color[3]);
+                        }
+                    });
+                    
+                    // Wave motion (ripple from the center, takes precedence over Motion 1)
+                    ui.separator();
+                    let wave_label = scene.text_cache.wave_label.get(scene.wave_state.active_count, |count| {
+                        if count > 0 {
+                            format!("Wave Motion (Active: {})", count)
                         } else {
-                            // No selection: toggle motion for all particles
-                            let all_particles: Vec<Entity> = queries.p3().iter().collect();
-                            
-                            if particles_with_motion.len() == all_particles.len() {
-                                // All particles are in motion: stop all
-                                for entity in all_particles {
-                                    commands.entity(entity).remove::<InMotion>();
+                            "Wave Motion".to_string()
+                        }
+                    });
+                    if ui.button(wave_label).clicked() {
+                        scene.wave_state.toggle_requested = true;
+                    }
+                    let mut amplitude = scene.wave_state.amplitude;
+                    if ui.add(egui::Slider::new(&mut amplitude, 0.0..=2.0)
+                        .text("Amplitude")
+                        .step_by(0.05)).changed() {
+                        scene.wave_state.amplitude = amplitude;
+                    }
+                    let mut wavelength = scene.wave_state.wavelength;
+                    if ui.add(egui::Slider::new(&mut wavelength, 0.1..=10.0)
+                        .text("Wavelength")
+                        .step_by(0.1)).changed() {
+                        scene.wave_state.wavelength = wavelength;
+                    }
+                    let mut wave_speed = scene.wave_state.speed;
+                    if ui.add(egui::Slider::new(&mut wave_speed, 0.0..=10.0)
+                        .text("Speed")
+                        .step_by(0.1)).changed() {
+                        scene.wave_state.speed = wave_speed;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Center:");
+                        let mut center = scene.wave_state.center;
+                        let mut center_changed = false;
+                        center_changed |= ui.add(egui::DragValue::new(&mut center.x)
+                            .speed(0.1)
+                            .prefix("X: ")).changed();
+                        center_changed |= ui.add(egui::DragValue::new(&mut center.y)
+                            .speed(0.1)
+                            .prefix("Z: ")).changed();
+                        if center_changed {
+                            scene.wave_state.center = center;
+                        }
+                    });
+                    
+                    // Jitter motion (random walk of the selection within the particle bounds)
+                    let mut jitter_active = scene.jitter_state.is_active;
+                    if ui.checkbox(&mut jitter_active, "Jitter Selection").changed() {
+                        scene.jitter_state.is_active = jitter_active;
+                    }
+                    let mut jitter_step = scene.jitter_state.step_size;
+                    if ui.add(egui::Slider::new(&mut jitter_step, 0.001..=0.2)
+                        .text("Step Size")
+                        .step_by(0.001)).changed() {
+                        scene.jitter_state.step_size = jitter_step;
+                    }
+                    ui.separator();
+                    
+                    // Attractor force (gravity well acting on the selection)
+                    let mut attractor_active = scene.attractor_state.is_active;
+                    if ui.checkbox(&mut attractor_active, "Attractor").changed() {
+                        scene.attractor_state.is_active = attractor_active;
+                    }
+                    ui.horizontal(|ui| {
+                        let mut position = scene.attractor_state.position;
+                        let mut position_changed = false;
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.x)
+                            .speed(0.1)
+                            .prefix("X: ")).changed();
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.y)
+                            .speed(0.1)
+                            .prefix("Y: ")).changed();
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.z)
+                            .speed(0.1)
+                            .prefix("Z: ")).changed();
+                        if position_changed {
+                            scene.attractor_state.position = position;
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        let mut repel = scene.attractor_state.repel;
+                        if ui.radio_value(&mut repel, false, "Attract").changed()
+                            | ui.radio_value(&mut repel, true, "Repel").changed() {
+                            scene.attractor_state.repel = repel;
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Falloff:");
+                        let mut falloff = scene.attractor_state.falloff;
+                        if ui.radio_value(&mut falloff, AttractorFalloff::InverseDistance, "1/d").changed()
+                            | ui.radio_value(&mut falloff, AttractorFalloff::Linear, "Linear").changed() {
+                            scene.attractor_state.falloff = falloff;
+                        }
+                    });
+                    let mut strength = scene.attractor_state.strength;
+                    if ui.add(egui::Slider::new(&mut strength, 0.0..=20.0)
+                        .text("Strength")
+                        .step_by(0.1)).changed() {
+                        scene.attractor_state.strength = strength;
+                    }
+                    let mut max_speed = scene.attractor_state.max_speed;
+                    if ui.add(egui::Slider::new(&mut max_speed, 0.1..=20.0)
+                        .text("Max Speed")
+                        .step_by(0.1)).changed() {
+                        scene.attractor_state.max_speed = max_speed;
+                    }
+                    ui.separator();
+                    
+                    // Emitter spawning particles with an initial velocity (pairs with Lifetime for fountains)
+                    let mut emitter_active = scene.emitter_state.is_active;
+                    if ui.checkbox(&mut emitter_active, "Emitter").changed() {
+                        scene.emitter_state.is_active = emitter_active;
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Position:");
+                        let mut position = scene.emitter_state.position;
+                        let mut position_changed = false;
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.x).speed(0.1).prefix("X: ")).changed();
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.y).speed(0.1).prefix("Y: ")).changed();
+                        position_changed |= ui.add(egui::DragValue::new(&mut position.z).speed(0.1).prefix("Z: ")).changed();
+                        if position_changed {
+                            scene.emitter_state.position = position;
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Direction:");
+                        let mut direction = scene.emitter_state.direction;
+                        let mut direction_changed = false;
+                        direction_changed |= ui.add(egui::DragValue::new(&mut direction.x).range(-1.0..=1.0).speed(0.01).prefix("X: ")).changed();
+                        direction_changed |= ui.add(egui::DragValue::new(&mut direction.y).range(-1.0..=1.0).speed(0.01).prefix("Y: ")).changed();
+                        direction_changed |= ui.add(egui::DragValue::new(&mut direction.z).range(-1.0..=1.0).speed(0.01).prefix("Z: ")).changed();
+                        if direction_changed {
+                            scene.emitter_state.direction = direction;
+                        }
+                    });
+                    let mut emit_rate = scene.emitter_state.rate;
+                    if ui.add(egui::Slider::new(&mut emit_rate, 1.0..=500.0)
+                        .text("Rate (/s)")
+                        .step_by(1.0)).changed() {
+                        scene.emitter_state.rate = emit_rate;
+                    }
+                    let mut emit_speed = scene.emitter_state.speed;
+                    if ui.add(egui::Slider::new(&mut emit_speed, 0.0..=20.0)
+                        .text("Speed")
+                        .step_by(0.1)).changed() {
+                        scene.emitter_state.speed = emit_speed;
+                    }
+                    let mut emit_spread = scene.emitter_state.spread_degrees;
+                    if ui.add(egui::Slider::new(&mut emit_spread, 0.0..=180.0)
+                        .text("Spread (deg)")
+                        .step_by(1.0)).changed() {
+                        scene.emitter_state.spread_degrees = emit_spread;
+                    }
+                    let mut emit_max = scene.emitter_state.max_count;
+                    if ui.add(egui::DragValue::new(&mut emit_max)
+                        .range(1..=100000)
+                        .prefix("Max Alive: ")).changed() {
+                        scene.emitter_state.max_count = emit_max;
+                    }
+                    ui.separator();
+                    
+                    // Flocking (boids) among particles added from the selection
+                    ui.label("Flocking");
+                    ui.horizontal(|ui| {
+                        let has_selected = !selection_state.selected_particles.is_empty();
+                        if ui.add_enabled(has_selected, egui::Button::new("Add Selection to Flock")).clicked() {
+                            scene.flocking_state.assign_requested = true;
+                        }
+                        if ui.button("Clear Flock").clicked() {
+                            scene.flocking_state.clear_requested = true;
+                        }
+                    });
+                    let mut separation = scene.flocking_state.separation_weight;
+                    if ui.add(egui::Slider::new(&mut separation, 0.0..=5.0)
+                        .text("Separation")
+                        .step_by(0.1)).changed() {
+                        scene.flocking_state.separation_weight = separation;
+                    }
+                    let mut alignment = scene.flocking_state.alignment_weight;
+                    if ui.add(egui::Slider::new(&mut alignment, 0.0..=5.0)
+                        .text("Alignment")
+                        .step_by(0.1)).changed() {
+                        scene.flocking_state.alignment_weight = alignment;
+                    }
+                    let mut cohesion = scene.flocking_state.cohesion_weight;
+                    if ui.add(egui::Slider::new(&mut cohesion, 0.0..=5.0)
+                        .text("Cohesion")
+                        .step_by(0.1)).changed() {
+                        scene.flocking_state.cohesion_weight = cohesion;
+                    }
+                    let mut flock_radius = scene.flocking_state.radius;
+                    if ui.add(egui::Slider::new(&mut flock_radius, 0.1..=5.0)
+                        .text("Radius")
+                        .step_by(0.1)).changed() {
+                        scene.flocking_state.radius = flock_radius;
+                    }
+                    let mut flock_speed = scene.flocking_state.max_speed;
+                    if ui.add(egui::Slider::new(&mut flock_speed, 0.1..=10.0)
+                        .text("Max Speed")
+                        .step_by(0.1)).changed() {
+                        scene.flocking_state.max_speed = flock_speed;
+                    }
+                    ui.separator();
+                    
+                    // Record and replay of every particle's motion (live motion pauses during replay)
+                    ui.label("Record and Replay");
+                    let playback_mode = scene.playback_state.mode;
+                    ui.horizontal(|ui| {
+                        match playback_mode {
+                            PlaybackMode::Live => {
+                                if ui.button("Record").clicked() {
+                                    scene.playback_state.record_requested = true;
                                 }
-                                motion1_state.is_active = false;
-                            } else {
-                                // Not all particles are in motion: start all
-                                for entity in all_particles {
-                                    if !particles_with_motion.contains(&entity) {
-                                        commands.entity(entity).insert(InMotion);
+                                let has_frames = !scene.playback_state.frames.is_empty();
+                                if ui.add_enabled(has_frames, egui::Button::new("Replay")).clicked() {
+                                    scene.playback_state.replay_requested = true;
+                                }
+                            }
+                            PlaybackMode::Recording => {
+                                if ui.button("Stop").clicked() {
+                                    scene.playback_state.stop_requested = true;
+                                }
+                                let tenths = (scene.playback_state.recorded_seconds() * 10.0) as i32;
+                                let recording_label = scene.text_cache.recording_time.get(tenths, |tenths| {
+                                    format!("Recording {:.1} s", tenths as f32 / 10.0)
+                                });
+                                ui.colored_label(egui::Color32::RED, recording_label);
+                            }
+                            PlaybackMode::Replaying => {
+                                let play_label = if scene.playback_state.is_playing { "Pause" } else { "Play" };
+                                if ui.button(play_label).clicked() {
+                                    let is_playing = !scene.playback_state.is_playing;
+                                    // Playing from the end starts over
+                                    if is_playing && scene.playback_state.playhead >= scene.playback_state.recorded_seconds() {
+                                        scene.playback_state.playhead = 0.0;
                                     }
+                                    scene.playback_state.is_playing = is_playing;
+                                }
+                                if ui.button("Exit Replay").clicked() {
+                                    scene.playback_state.exit_replay_requested = true;
                                 }
-                                motion1_state.is_active = true;
                             }
                         }
+                    });
+                    if playback_mode == PlaybackMode::Replaying {
+                        // Scrubbing pauses playback at the chosen time
+                        let mut playhead = scene.playback_state.playhead;
+                        if ui.add(egui::Slider::new(&mut playhead, 0.0..=scene.playback_state.recorded_seconds())
+                            .text("Time (s)")
+                            .step_by(0.01)).changed() {
+                            scene.playback_state.playhead = playhead;
+                            scene.playback_state.is_playing = false;
+                        }
                     }
+                    ui.add_enabled_ui(playback_mode == PlaybackMode::Live, |ui| {
+                        let mut playback_duration = scene.playback_state.duration;
+                        if ui.add(egui::Slider::new(&mut playback_duration, 1.0..=120.0)
+                            .text("Length (s)")
+                            .step_by(1.0)).changed() {
+                            scene.playback_state.duration = playback_duration;
+                        }
+                        ui.horizontal(|ui| {
+                            ui.label("Max Frames:");
+                            let mut max_frames = scene.playback_state.max_frames;
+                            if ui.add(egui::DragValue::new(&mut max_frames)
+                                .range(2..=100000)
+                                .speed(10)).changed() {
+                                scene.playback_state.max_frames = max_frames;
+                            }
+                            ui.label("Every N Frames:");
+                            let mut sample_interval = scene.playback_state.sample_interval;
+                            if ui.add(egui::DragValue::new(&mut sample_interval)
+                                .range(1..=120)
+                                .speed(1)).changed() {
+                                scene.playback_state.sample_interval = sample_interval;
+                            }
+                        });
+                    });
+                    let recorded_frames = scene.text_cache.recorded_frames.get(scene.playback_state.frames.len(), |count| {
+                        format!("Recorded Frames: {}", count)
+                    });
+                    ui.label(recorded_frames);
+                    ui.separator();
                     
-                    // Show Trajectory button
-                    let trajectory_label = if trajectory_state.is_visible { "Hide Trajectory" } else { "Show Trajectory" };
-                    if ui.button(trajectory_label).clicked() {
-                        trajectory_state.is_visible = !trajectory_state.is_visible;
+                    // Velocity arrows (particles with a velocity)
+                    let mut show_arrows = scene.velocity_arrow_state.is_visible;
+                    if ui.checkbox(&mut show_arrows, "Show Velocity Arrows").changed() {
+                        scene.velocity_arrow_state.is_visible = show_arrows;
+                    }
+                    let mut arrow_scale = scene.velocity_arrow_state.length_scale;
+                    if ui.add(egui::Slider::new(&mut arrow_scale, 0.05..=5.0)
+                        .text("Arrow Scale")
+                        .step_by(0.05)).changed() {
+                        scene.velocity_arrow_state.length_scale = arrow_scale;
+                    }
+                    
+                    // Proximity lines between near particles (neighbors from the spatial grid)
+                    ui.horizontal(|ui| {
+                        let mut show_lines = scene.proximity_state.is_visible;
+                        if ui.checkbox(&mut show_lines, "Proximity Lines").changed() {
+                            scene.proximity_state.is_visible = show_lines;
+                        }
+                        let mut line_color = scene.proximity_state.color.to_srgba().to_f32_array();
+                        if ui.color_edit_button_rgba_unmultiplied(&mut line_color).changed() {
+                            scene.proximity_state.color = Color::srgba(line_color[0], line_color[1], line_color[2], line_color[3]);
+                        }
+                    });
+                    let mut threshold = scene.proximity_state.threshold;
+                    if ui.add(egui::Slider::new(&mut threshold, 0.01..=2.0)
+                        .text("Distance (m)")
+                        .step_by(0.01)).changed() {
+                        scene.proximity_state.threshold = threshold;
+                    }
+                    let mut max_edges = scene.proximity_state.max_edges;
+                    if ui.add(egui::DragValue::new(&mut max_edges)
+                        .range(1..=100000)
+                        .prefix("Max Lines: ")).changed() {
+                        scene.proximity_state.max_edges = max_edges;
+                    }
+                    if scene.proximity_state.capped {
+                        ui.colored_label(egui::Color32::YELLOW, format!("Line cap reached ({})", scene.proximity_state.max_edges));
+                    }
+                    
+                    // Layers section
+                    ui.separator();
+                    ui.label("Layers");
+                    
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut scene.layer_state.new_layer_name)
+                            .desired_width(100.0)
+                            .hint_text("Layer name"));
+                        if ui.button("Create Layer").clicked() {
+                            scene.layer_state.create_requested = true;
+                        }
+                    });
+                    
+                    let has_selected = !selection_state.selected_particles.is_empty();
+                    let layer_state = &mut *scene.layer_state;
+                    for (index, layer) in layer_state.layers.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.checkbox(&mut layer.visible, format!("{} ({})", layer.name, layer.members.len())).changed() {
+                                layer_state.visibility_changed = true;
+                            }
+                            if ui.add_enabled(has_selected, egui::Button::new("Assign Selected")).clicked() {
+                                layer_state.assign_selection_requested = Some(index);
+                            }
+                        });
                     }
                     
                         }); // Close vertical layout
@@ -480,7 +1753,7 @@ pub fn egui_controls_ui(
                         // Add spacing between buttons
                         ui.add_space(5.0);
                         // Streams button with same style
-                        if ui.button("Streams").clicked() {
+                        if ui.button("Streams").on_hover_text("F2").clicked() {
                             streams_panel_state.is_visible = true;
                         }
                     });
@@ -531,17 +1804,17 @@ pub fn egui_controls_ui(
                     // Add spacing between buttons
                     ui.add_space(5.0);
                     // 3D Viewer toggle button
-                    if ui.button("3D Viewer").clicked() {
+                    if ui.button("3D Viewer").on_hover_text("F3").clicked() {
                         layout_state.d3_viewer_visible = !layout_state.d3_viewer_visible;
                     }
                     ui.add_space(5.0);
                     // Left Panel toggle button
-                    if ui.button("Middle-Left Panel").clicked() {
+                    if ui.button("Middle-Left Panel").on_hover_text("F4").clicked() {
                         layout_state.left_half_panel_collapsed = !layout_state.left_half_panel_collapsed;
                     }
                         ui.add_space(5.0);
                         // Button with normal frame to make it visible (not frame(false))
-                        if ui.button("Inspector").clicked() {
+                        if ui.button("Inspector").on_hover_text("F1").clicked() {
                             layout_state.inspector_collapsed = !layout_state.inspector_collapsed;
                         }
                         
@@ -597,6 +1870,107 @@ pub fn egui_controls_ui(
                         ui.vertical(|ui| {
                             ui.heading("Inspector");
                             ui.separator();
+                            
+                            // Selection summary (shared with the bounding box)
+                            let stats = &scene.selection_stats;
+                            let text_cache = &mut scene.text_cache;
+                            ui.label(text_cache.inspector_selected.get(stats.count, |count| format!("Selected: {}", count)));
+                            if stats.count > 0 {
+                                ui.label(text_cache.centroid.get(stats.centroid, |centroid| format!("Centroid: ({:.2}, {:.2}, {:.2})", centroid.x, centroid.y, centroid.z)));
+                                ui.label(text_cache.size.get(stats.max - stats.min, |size| format!("Size: ({:.2}, {:.2}, {:.2})", size.x, size.y, size.z)));
+                            }
+                            
+                            // Exact position of a single selected particle (its centroid is its position)
+                            let single = stats.count == 1;
+                            let mut position = if single { stats.centroid } else { Vec3::ZERO };
+                            let mut position_changed = false;
+                            ui.add_enabled_ui(single, |ui| {
+                                ui.label("Position");
+                                ui.horizontal(|ui| {
+                                    position_changed |= ui.add(egui::DragValue::new(&mut position.x)
+                                        .speed(0.01)
+                                        .prefix("X: ")).changed();
+                                    position_changed |= ui.add(egui::DragValue::new(&mut position.y)
+                                        .speed(0.01)
+                                        .prefix("Y: ")).changed();
+                                    position_changed |= ui.add(egui::DragValue::new(&mut position.z)
+                                        .speed(0.01)
+                                        .prefix("Z: ")).changed();
+                                });
+                            });
+                            if position_changed {
+                                selection_transform_state.exact_position_requested = Some(position);
+                            }
+                            
+                            // Live scene counts
+                            ui.separator();
+                            let mut show_stats = layout_state.inspector_show_stats;
+                            if ui.checkbox(&mut show_stats, "Scene Statistics").changed() {
+                                layout_state.inspector_show_stats = show_stats;
+                            }
+                            if layout_state.inspector_show_stats {
+                                // Total, In Motion and Locked are archetype sizes, so no particle is visited;
+                                // visibility is not, so the hidden particles are recounted on an interval
+                                let text_cache = &mut scene.text_cache;
+                                let now = ui.input(|input| input.time);
+                                if text_cache.hidden_counted_at.is_none_or(|counted_at| now - counted_at >= HIDDEN_COUNT_INTERVAL_SECONDS) {
+                                    text_cache.hidden_counted_at = Some(now);
+                                    let hidden = queries.p5().iter().filter(|visibility| **visibility == Visibility::Hidden).count();
+                                    text_cache.hidden_count.get(hidden, |count| format!("Hidden: {}", count));
+                                }
+                                ui.label(text_cache.total_count.get(queries.p3().iter().len(), |count| format!("Total: {}", count)));
+                                ui.label(text_cache.inspector_selected.get(scene.selection_stats.count, |count| format!("Selected: {}", count)));
+                                ui.label(text_cache.in_motion_count.get(queries.p2().iter().len(), |count| format!("In Motion: {}", count)));
+                                ui.label(text_cache.locked_count.get(queries.p4().iter().len(), |count| format!("Locked: {}", count)));
+                                ui.label(text_cache.hidden_count.text());
+                                // Nearest-neighbor distances (refreshed periodically, not every frame)
+                                match scene.neighbor_stats.mean {
+                                    Some(mean) => {
+                                        ui.label(text_cache.neighbor_mean.get(mean, |mean| format!("Nearest Neighbor Mean: {:.3} m", mean)));
+                                        let range = (scene.neighbor_stats.min, scene.neighbor_stats.max);
+                                        ui.label(text_cache.neighbor_range.get(range, |(min, max)| format!("Nearest Neighbor Min/Max: {:.3} / {:.3} m", min, max)));
+                                    }
+                                    None => {
+                                        ui.label("Nearest Neighbor: N/A");
+                                    }
+                                }
+                                if !scene.layer_state.layers.is_empty() {
+                                    ui.separator();
+                                    text_cache.layer_counts.resize_with(scene.layer_state.layers.len(), CachedText::default);
+                                    for (layer, layer_count) in scene.layer_state.layers.iter().zip(text_cache.layer_counts.iter_mut()) {
+                                        ui.label(layer_count.get(layer.members.len(), |count| format!("{}: {}", layer.name, count)));
+                                    }
+                                }
+                            }
+                            
+                            // Legend for the color-by mode (same colormap function as the particles)
+                            let color_by = &scene.color_by_state;
+                            if color_by.field != ColorByField::None {
+                                ui.separator();
+                                let unit = if color_by.field == ColorByField::Speed { "m/s" } else { "m" };
+                                let text_cache = &mut scene.text_cache;
+                                ui.label(text_cache.legend_title.get((color_by.field, color_by.colormap), |(field, colormap)| format!("{} ({})", field.label(), colormap.label())));
+                                ui.horizontal(|ui| {
+                                    let (bar, _) = ui.allocate_exact_size(egui::vec2(COLOR_LEGEND_SIZE[0], COLOR_LEGEND_SIZE[1]), egui::Sense::hover());
+                                    let slice_height = bar.height() / COLOR_LEGEND_STEPS as f32;
+                                    for step in 0..COLOR_LEGEND_STEPS {
+                                        // Top of the bar is the maximum
+                                        let t = 1.0 - (step as f32 + 0.5) / COLOR_LEGEND_STEPS as f32;
+                                        let [r, g, b, _] = crate::systems::colormap::colormap_color(color_by.colormap, t).to_srgba().to_u8_array();
+                                        let slice = egui::Rect::from_min_size(
+                                            egui::pos2(bar.left(), bar.top() + step as f32 * slice_height),
+                                            egui::vec2(bar.width(), slice_height + 0.5),
+                                        );
+                                        ui.painter().rect_filled(slice, 0.0, egui::Color32::from_rgb(r, g, b));
+                                    }
+                                    ui.vertical(|ui| {
+                                        ui.set_height(bar.height());
+                                        ui.label(text_cache.legend_max.get((color_by.max, unit), |(max, unit)| format!("{:.2} {}", max, unit)));
+                                        ui.add_space((bar.height() - 2.0 * ui.text_style_height(&egui::TextStyle::Body) - ui.spacing().item_spacing.y * 2.0).max(0.0));
+                                        ui.label(text_cache.legend_min.get((color_by.min, unit), |(min, unit)| format!("{:.2} {}", min, unit)));
+                                    });
+                                });
+                            }
                         });
                     });
                 });
@@ -745,5 +2119,64 @@ pub fn egui_controls_ui(
                     });
                 });
         }
+        
+        // Notifications - stacked in the top-right corner of the 3D viewport, newest at the bottom
+        if !scene.notifications.messages.is_empty() {
+            let viewport_right_edge = if layout_state.inspector_collapsed {
+                ctx.viewport_rect().right()
+            } else {
+                layout_state.right_panel_start_x
+            };
+            egui::Area::new(egui::Id::new("notifications"))
+                .fixed_pos(egui::pos2(viewport_right_edge - NOTIFICATION_WIDTH - MINIMAP_MARGIN, layout_state.top_bars_height + MINIMAP_MARGIN))
+                .order(egui::Order::Foreground)
+                .interactable(false)
+                .show(ctx, |ui| {
+                    ui.set_width(NOTIFICATION_WIDTH);
+                    for notification in scene.notifications.messages.iter() {
+                        let opacity = (notification.remaining / NOTIFICATION_FADE_SECONDS).clamp(0.0, 1.0);
+                        ui.scope(|ui| {
+                            ui.multiply_opacity(opacity);
+                            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                                ui.set_width(NOTIFICATION_WIDTH);
+                                if notification.count > 1 {
+                                    ui.label(format!("{} (x{})", notification.message, notification.count));
+                                } else {
+                                    ui.label(&notification.message);
+                                }
+                            });
+                        });
+                    }
+                });
+            // Keep repainting while messages count down and fade
+            ctx.request_repaint();
+        }
+        
+        // Minimap - bottom-right corner of the 3D viewport
+        if scene.minimap_state.is_visible && layout_state.d3_viewer_visible && !streams_panel_state.is_visible {
+            let viewport_rect = ctx.viewport_rect();
+            let viewport_right_edge = if layout_state.inspector_collapsed {
+                viewport_rect.right()
+            } else {
+                layout_state.right_panel_start_x
+            };
+            let viewport_bottom = viewport_rect.bottom() - layout_state.bottom_bar_height;
+            let size = scene.minimap_state.size;
+            let minimap_rect = egui::Rect::from_min_size(
+                egui::pos2(viewport_right_edge - size - MINIMAP_MARGIN, viewport_bottom - size - MINIMAP_MARGIN),
+                egui::vec2(size, size)
+            );
+            
+            let camera_query = queries.p0();
+            let camera = camera_query.single().ok().map(|(_, transform, _, projection)| (transform, projection));
+            egui::Area::new(egui::Id::new("minimap"))
+                .fixed_pos(minimap_rect.min)
+                .constrain(true)
+                .show(ctx, |ui| {
+                    // Allocate rect to intercept clicks and block 3D world input
+                    let _response = ui.allocate_rect(minimap_rect, egui::Sense::click());
+                    draw_minimap(ui.painter(), minimap_rect, &scene.minimap_state, &scene.particle_positions, &selection_state.selected_particles, camera);
+                });
+        }
     }
 }
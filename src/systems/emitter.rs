@@ -0,0 +1,112 @@
+// systems/emitter.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Emitted, EmitterState, Velocity, Locked, InFlock, MaxParticles, PendingSpawns, ParticlePositions, ParticleBoundsState, ParticleGroupState, ParticleRng, SelectionTransformState, AttractorState, ParticleSelectionState};
+use crate::constants::{COLOR_WHITE, EMITTER_COLOR, EMITTER_MARKER_RADIUS};
+use crate::systems::particle_creation::{spawn_single_particle, SpawnSettings};
+use crate::systems::selection_transform::{bake_base_position, shift_selection_original};
+use rand::Rng;
+
+/// System to spawn particles from the emitter at a steady rate
+/// The fractional spawn budget carries over between frames, so the rate holds at any frame rate
+pub fn emit_particles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut max_particles: ResMut<MaxParticles>,
+    mut particle_rng: ResMut<ParticleRng>,
+    time: Res<Time>,
+    emitter_state: Res<EmitterState>,
+    pending_spawns: Res<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
+    emitted_query: Query<(), With<Emitted>>,
+    // Spawn budget carried between frames and the cached spawn material
+    mut emitter: Local<(f32, Option<Handle<StandardMaterial>>)>,
+) {
+    let (emit_budget, white_material) = &mut *emitter;
+    if !emitter_state.is_active {
+        *emit_budget = 0.0;
+        return;
+    }
+    
+    *emit_budget += emitter_state.rate * time.delta_secs();
+    let count = emit_budget.floor() as usize;
+    if count == 0 {
+        return;
+    }
+    *emit_budget -= count as f32;
+    
+    // Stop at the emitter's own limit and at the global cap
+    let total = particle_positions.particle_count() + pending_spawns.positions.len();
+    let cap_remaining = max_particles.remaining(total);
+    let remaining = cap_remaining.min(emitter_state.max_count.saturating_sub(emitted_query.iter().count()));
+    max_particles.clamped = count > cap_remaining;
+    let count = count.min(remaining);
+    
+    let material = white_material.get_or_insert_with(|| materials.add(COLOR_WHITE)).clone();
+    let shape = spawn_settings.shape_state.shape;
+    let mesh = spawn_settings.shape_state.mesh(shape, &mut meshes);
+    
+    // Directions are sampled uniformly inside the cone around the emission direction
+    let cone_rotation = Quat::from_rotation_arc(Vec3::Y, emitter_state.direction.normalize_or(Vec3::Y));
+    let cos_spread = emitter_state.spread_degrees.to_radians().cos();
+    for _ in 0..count {
+        let cos_theta = particle_rng.rng.gen_range(cos_spread..=1.0);
+        let sin_theta = (1.0 - cos_theta * cos_theta).max(0.0).sqrt();
+        let phi = particle_rng.rng.gen_range(0.0..std::f32::consts::TAU);
+        let direction = cone_rotation * Vec3::new(sin_theta * phi.cos(), cos_theta, sin_theta * phi.sin());
+        
+        let entity = spawn_single_particle(
+            &mut commands,
+            &mut particle_positions,
+            emitter_state.position,
+            shape,
+            &mesh,
+            &material,
+            &spawn_settings,
+        );
+        commands.entity(entity).insert((Emitted, Velocity(direction * emitter_state.speed)));
+    }
+}
+
+/// System to move emitted particles along their velocity
+/// Flock members and selected particles under an active attractor are skipped,
+/// since those systems already integrate the same Velocity
+pub fn move_emitted_particles(
+    time: Res<Time>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+    attractor_state: Res<AttractorState>,
+    selection_state: Res<ParticleSelectionState>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut particle_query: Query<(Entity, &mut Transform, &Velocity), (With<Emitted>, Without<Locked>, Without<InFlock>)>,
+) {
+    let delta_time = time.delta_secs();
+    for (entity, mut transform, velocity) in particle_query.iter_mut() {
+        if attractor_state.is_active && selection_state.selected_particles.contains(&entity) {
+            continue;
+        }
+        let step = velocity.0 * delta_time;
+        transform.translation += step;
+        // Base positions follow, so bounds and group changes do not pull particles back to the emitter
+        bake_base_position(entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+        shift_selection_original(&mut transform_state, entity, step);
+    }
+}
+
+/// System to mark the emitter position and direction while it is active
+pub fn draw_emitter(
+    mut gizmos: Gizmos,
+    emitter_state: Res<EmitterState>,
+) {
+    if !emitter_state.is_active {
+        return;
+    }
+    
+    let position = emitter_state.position;
+    gizmos.sphere(Isometry3d::from_translation(position), EMITTER_MARKER_RADIUS, EMITTER_COLOR);
+    gizmos.arrow(position, position + emitter_state.direction.normalize_or(Vec3::Y) * 0.5, EMITTER_COLOR);
+}
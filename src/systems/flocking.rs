@@ -0,0 +1,165 @@
+// systems/flocking.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{NeighborStats, Particle, InFlock, FlockingState, Velocity, Locked, SpatialGrid, ParticlePositions, ParticleSelectionState, SelectionTransformState, ParticleBoundsState, ParticleGroupState, ParticleRng};
+use crate::systems::selection_transform::{shift_selection_original, bake_base_position};
+use rand::Rng;
+
+/// System to rebuild the spatial grid when particle positions change
+pub fn update_spatial_grid(
+    particle_positions: Res<ParticlePositions>,
+    mut spatial_grid: ResMut<SpatialGrid>,
+) {
+    if !particle_positions.is_changed() {
+        return;
+    }
+    
+    spatial_grid.rebuild(particle_positions.current_positions.iter().map(|(entity, position)| (*entity, *position)));
+}
+
+/// System to recompute the nearest-neighbor distance statistics on a throttled interval
+/// Runs after the grid rebuild; every particle searches the grid for its closest neighbor
+pub fn update_neighbor_stats(
+    time: Res<Time>,
+    spatial_grid: Res<SpatialGrid>,
+    mut neighbor_stats: ResMut<NeighborStats>,
+    mut since_update: Local<Option<f32>>,
+) {
+    let elapsed = since_update.map_or(f32::MAX, |seconds| seconds + time.delta_secs());
+    if elapsed < crate::constants::NEIGHBOR_STATS_INTERVAL_SECONDS {
+        *since_update = Some(elapsed);
+        return;
+    }
+    *since_update = Some(0.0);
+    
+    let mut count = 0;
+    let mut sum = 0.0;
+    let mut min = f32::MAX;
+    let mut max = 0.0_f32;
+    for &(entity, position) in spatial_grid.cells.values().flatten() {
+        let Some(distance) = spatial_grid.nearest_neighbor_distance(entity, position) else { continue };
+        count += 1;
+        sum += distance;
+        min = min.min(distance);
+        max = max.max(distance);
+    }
+    
+    *neighbor_stats = if count > 0 {
+        NeighborStats { mean: Some(sum / count as f32), min, max }
+    } else {
+        NeighborStats::default()
+    };
+}
+
+/// System to add the selection to the flock (with a small seeded starting velocity) or release all
+pub fn handle_flock_assignment(
+    mut commands: Commands,
+    mut flocking_state: ResMut<FlockingState>,
+    selection_state: Res<ParticleSelectionState>,
+    mut particle_rng: ResMut<ParticleRng>,
+    flock_query: Query<Entity, With<InFlock>>,
+) {
+    if flocking_state.clear_requested {
+        flocking_state.clear_requested = false;
+        for entity in flock_query.iter() {
+            commands.entity(entity).remove::<InFlock>().insert(Velocity::default());
+        }
+    }
+    
+    if flocking_state.assign_requested {
+        flocking_state.assign_requested = false;
+        
+        let selected: Vec<Entity> = selection_state.sorted().into_iter()
+            .filter(|entity| !flock_query.contains(*entity))
+            .collect();
+        
+        let speed = flocking_state.max_speed * 0.5;
+        for entity in selected {
+            let velocity = Vec3::new(
+                particle_rng.rng.gen_range(-speed..=speed),
+                particle_rng.rng.gen_range(-speed..=speed),
+                particle_rng.rng.gen_range(-speed..=speed),
+            );
+            commands.entity(entity).insert((InFlock, Velocity(velocity)));
+        }
+    }
+}
+
+/// System to steer flock particles with separation, alignment and cohesion
+/// Neighbors come from the spatial grid, so each particle only looks at nearby cells
+pub fn animate_flocking(
+    time: Res<Time>,
+    flocking_state: Res<FlockingState>,
+    spatial_grid: Res<SpatialGrid>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut flock_query: Query<(Entity, &mut Transform, &mut Velocity), (With<Particle>, With<InFlock>, Without<Locked>)>,
+) {
+    if flock_query.is_empty() {
+        return;
+    }
+    
+    let delta_time = time.delta_secs();
+    let radius = flocking_state.radius;
+    
+    // Read all velocities first so every boid steers from the same snapshot
+    let velocities: std::collections::HashMap<Entity, Vec3> = flock_query.iter()
+        .map(|(entity, _, velocity)| (entity, velocity.0))
+        .collect();
+    
+    let mut new_velocities = Vec::with_capacity(velocities.len());
+    for (entity, transform, velocity) in flock_query.iter() {
+        let position = transform.translation;
+        let mut separation = Vec3::ZERO;
+        let mut velocity_sum = Vec3::ZERO;
+        let mut position_sum = Vec3::ZERO;
+        let mut neighbor_count = 0;
+        
+        spatial_grid.for_each_within(position, radius, |other, other_position| {
+            let Some(other_velocity) = velocities.get(&other) else { return };
+            if other == entity {
+                return;
+            }
+            let offset = position - other_position;
+            let distance_squared = offset.length_squared().max(1e-4);
+            separation += offset / distance_squared;
+            velocity_sum += *other_velocity;
+            position_sum += other_position;
+            neighbor_count += 1;
+        });
+        
+        let mut steering = separation * flocking_state.separation_weight;
+        if neighbor_count > 0 {
+            let count = neighbor_count as f32;
+            steering += (velocity_sum / count - velocity.0) * flocking_state.alignment_weight;
+            steering += (position_sum / count - position) * flocking_state.cohesion_weight;
+        }
+        
+        let new_velocity = (velocity.0 + steering * delta_time).clamp_length_max(flocking_state.max_speed);
+        new_velocities.push((entity, new_velocity));
+    }
+    
+    for (entity, new_velocity) in new_velocities {
+        let Ok((_, mut transform, mut velocity)) = flock_query.get_mut(entity) else { continue };
+        
+        // Keep the flock inside the particle bounds, bouncing off the walls
+        let target = transform.translation + new_velocity * delta_time;
+        let clamped = bounds_state.clamp_world(target);
+        let mut bounced_velocity = new_velocity;
+        for axis in 0..3 {
+            if clamped[axis] != target[axis] {
+                bounced_velocity[axis] = -bounced_velocity[axis];
+            }
+        }
+        velocity.0 = bounced_velocity;
+        
+        let step = clamped - transform.translation;
+        transform.translation = clamped;
+        // Base positions follow, so bounds and group changes keep the flock where it flew
+        bake_base_position(entity, clamped, &mut particle_positions, &bounds_state, &group_state);
+        shift_selection_original(&mut transform_state, entity, step);
+    }
+}
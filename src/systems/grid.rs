@@ -2,63 +2,157 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{GridState, GridLine};
+use crate::components::{GridState, SelectionStats, GroundPlane, GroundPlaneState, AxisLabel, AxisLabelState, OrientationGizmoState, RightCamera};
+use crate::constants::{AXIS_LENGTH, AXIS_LABEL_FONT_SIZE, AXIS_LABEL_OFFSET, ORIENTATION_GIZMO_MARGIN, ORIENTATION_GIZMO_SIZE, COLOR_RED, COLOR_GREEN, COLOR_BLUE, GRID_COLOR, GRID_SPACING};
 
-pub fn update_grid_dimensions(
+/// Draws the RGB axes from the origin each frame using gizmos
+pub fn draw_axes(mut gizmos: Gizmos) {
+    gizmos.line(Vec3::ZERO, Vec3::X * AXIS_LENGTH, COLOR_RED);
+    gizmos.line(Vec3::ZERO, Vec3::Y * AXIS_LENGTH, COLOR_GREEN);
+    gizmos.line(Vec3::ZERO, Vec3::Z * AXIS_LENGTH, COLOR_BLUE);
+}
+
+/// Keeps the X/Y/Z labels at the axis tips, projected through the 3D camera onto the screen
+/// Labels are hidden while their tip is behind the camera or outside its viewport
+pub fn update_axis_labels(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    mut grid_state: ResMut<GridState>,
-    grid_line_query: Query<Entity, With<GridLine>>,
+    label_state: Res<AxisLabelState>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<RightCamera>>,
+    mut label_query: Query<(Entity, &AxisLabel, &mut Node, &mut Visibility)>,
 ) {
-    // Check if grid dimensions changed
-    if grid_state.size_x != grid_state.previous_size_x || grid_state.size_z != grid_state.previous_size_z {
-        // Despawn all existing grid lines
-        for entity in grid_line_query.iter() {
+    if !label_state.is_visible {
+        for (entity, _, _, _) in label_query.iter() {
             commands.entity(entity).despawn();
         }
-        
-        // Update previous values
-        grid_state.previous_size_x = grid_state.size_x;
-        grid_state.previous_size_z = grid_state.size_z;
-        
-        // Respawn grid with new dimensions
-        // Extract values from grid_state
-        let size_x = grid_state.size_x;
-        let size_z = grid_state.size_z;
-        
-        // Spawn grid lines directly here instead of calling spawn_grid
-        let size_x_f = size_x as f32;
-        let size_z_f = size_z as f32;
-        let half_size_x = size_x_f / 2.0;
-        let half_size_z = size_z_f / 2.0;
-        let num_lines_x = size_x + 1;
-        let num_lines_z = size_z + 1;
-        
-        use crate::constants::{GRID_LINE_RADIUS, GRID_COLOR, GRID_SPACING};
-        
-        // Create grid lines along X axis (parallel to Z) - these lines span the X direction
-        for i in 0..num_lines_z {
-            let z = -half_size_z + (i as f32 * GRID_SPACING);
+        return;
+    }
+    
+    if label_query.is_empty() {
+        for (label, axis, color) in [("X", Vec3::X, COLOR_RED), ("Y", Vec3::Y, COLOR_GREEN), ("Z", Vec3::Z, COLOR_BLUE)] {
             commands.spawn((
-                Mesh3d(meshes.add(Cylinder::new(GRID_LINE_RADIUS, size_x_f))),
-                MeshMaterial3d(materials.add(GRID_COLOR)),
-                Transform::from_translation(Vec3::new(0.0, 0.0, z))
-                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
-                GridLine,
+                Text::new(label),
+                TextFont {
+                    font_size: AXIS_LABEL_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(color),
+                Node {
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                // Positioned from the camera next frame
+                Visibility::Hidden,
+                AxisLabel { tip: axis * AXIS_LENGTH },
             ));
         }
-        
-        // Create grid lines along Z axis (parallel to X) - these lines span the Z direction
-        for i in 0..num_lines_x {
-            let x = -half_size_x + (i as f32 * GRID_SPACING);
-            commands.spawn((
-                Mesh3d(meshes.add(Cylinder::new(GRID_LINE_RADIUS, size_z_f))),
-                MeshMaterial3d(materials.add(GRID_COLOR)),
-                Transform::from_translation(Vec3::new(x, 0.0, 0.0))
-                    .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
-                GridLine,
-            ));
+        return;
+    }
+    
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let viewport_rect = camera.logical_viewport_rect();
+    
+    for (_, label, mut node, mut visibility) in label_query.iter_mut() {
+        // world_to_viewport fails for points behind the camera, and returns window coordinates
+        let screen_position = camera.world_to_viewport(camera_transform, label.tip).ok()
+            .filter(|position| viewport_rect.is_some_and(|rect| rect.contains(*position)));
+        match screen_position {
+            Some(position) => {
+                // Slightly offset so the text sits beside the tip instead of on it
+                node.left = Val::Px(position.x + AXIS_LABEL_OFFSET);
+                node.top = Val::Px(position.y - AXIS_LABEL_OFFSET - AXIS_LABEL_FONT_SIZE);
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
         }
     }
 }
+
+/// Draws small world-aligned RGB axes just in front of the camera, placed so they appear
+/// in the bottom-left corner of the viewport, so they turn with the camera like a CAD view cube
+pub fn draw_orientation_gizmo(
+    mut gizmos: Gizmos,
+    gizmo_state: Res<OrientationGizmoState>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<RightCamera>>,
+) {
+    if !gizmo_state.is_visible {
+        return;
+    }
+    
+    let Ok((camera, camera_transform)) = camera_query.single() else { return };
+    let Some(rect) = camera.logical_viewport_rect() else { return };
+    
+    // Rays through the corner anchor and a point one gizmo size to its right give the
+    // world length that covers ORIENTATION_GIZMO_SIZE pixels at a short distance from the camera
+    let anchor = Vec2::new(rect.min.x + ORIENTATION_GIZMO_MARGIN, rect.max.y - ORIENTATION_GIZMO_MARGIN);
+    let (Ok(anchor_ray), Ok(edge_ray)) = (
+        camera.viewport_to_world(camera_transform, anchor),
+        camera.viewport_to_world(camera_transform, anchor + Vec2::X * ORIENTATION_GIZMO_SIZE),
+    ) else { return };
+    let center = anchor_ray.get_point(1.0);
+    let length = center.distance(edge_ray.get_point(1.0));
+    
+    gizmos.line(center, center + Vec3::X * length, COLOR_RED);
+    gizmos.line(center, center + Vec3::Y * length, COLOR_GREEN);
+    gizmos.line(center, center + Vec3::Z * length, COLOR_BLUE);
+}
+
+/// Draws the ground grid each frame from GridState using gizmos
+/// Immediate-mode drawing means dimension and offset changes need no despawn/respawn
+pub fn draw_grid(mut gizmos: Gizmos, grid_state: Res<GridState>) {
+    let size_x = grid_state.size_x as f32;
+    let size_z = grid_state.size_z as f32;
+    let half_size_x = size_x / 2.0;
+    let half_size_z = size_z / 2.0;
+    let num_lines_x = grid_state.size_x + 1;
+    let num_lines_z = grid_state.size_z + 1;
+    let Vec2 { x: center_x, y: center_z } = grid_state.offset;
+    
+    // Grid lines along X axis (parallel to Z) - these lines span the X direction
+    for i in 0..num_lines_z {
+        let z = center_z - half_size_z + (i as f32 * GRID_SPACING);
+        gizmos.line(Vec3::new(center_x - half_size_x, 0.0, z), Vec3::new(center_x + half_size_x, 0.0, z), GRID_COLOR);
+    }
+    
+    // Grid lines along Z axis (parallel to X) - these lines span the Z direction
+    for i in 0..num_lines_x {
+        let x = center_x - half_size_x + (i as f32 * GRID_SPACING);
+        gizmos.line(Vec3::new(x, 0.0, center_z - half_size_z), Vec3::new(x, 0.0, center_z + half_size_z), GRID_COLOR);
+    }
+}
+
+/// Keeps the grid centered under the selection centroid while following is enabled
+/// The offset is only written when it moves, so the ground plane is not rebuilt every frame;
+/// with nothing selected the grid stays where it was
+pub fn follow_selection_with_grid(
+    mut grid_state: ResMut<GridState>,
+    selection_stats: Res<SelectionStats>,
+) {
+    if !grid_state.follow_selection || selection_stats.count == 0 {
+        return;
+    }
+    
+    let centroid = Vec2::new(selection_stats.centroid.x, selection_stats.centroid.z);
+    if grid_state.offset != centroid {
+        grid_state.offset = centroid;
+    }
+}
+
+/// Shows/hides the ground plane and resizes (and moves) it to match the grid
+pub fn update_ground_plane(
+    grid_state: Res<GridState>,
+    plane_state: Res<GroundPlaneState>,
+    mut plane_query: Query<(&mut Transform, &mut Visibility), With<GroundPlane>>,
+) {
+    if !grid_state.is_changed() && !plane_state.is_changed() {
+        return;
+    }
+    
+    for (mut transform, mut visibility) in plane_query.iter_mut() {
+        transform.scale = Vec3::new(grid_state.size_x as f32, 1.0, grid_state.size_z as f32);
+        transform.translation.x = grid_state.offset.x;
+        transform.translation.z = grid_state.offset.y;
+        *visibility = if plane_state.is_visible { Visibility::Visible } else { Visibility::Hidden };
+    }
+}
@@ -0,0 +1,309 @@
+// systems/io.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+use crate::components::{IoState, ParticleSelectionState, TrajectoryTrail, CameraProjectionState, Notifications, Particle, Selected, ParticleBaseColor, ParticlePositions, MaxParticles, PendingSpawns, SelectionChanged};
+use crate::systems::{notify, spawn_single_particle, SpawnSettings};
+use crate::constants::{CONFIG_FILE_PATH, FOV_MIN_DEGREES, FOV_MAX_DEGREES, COLOR_WHITE};
+
+/// Settings persisted in the config file, one `key = value` per line
+/// Unknown keys and unparsable values are ignored so older or hand-edited files still load
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AppConfig {
+    pub fov_degrees: Option<f32>,
+}
+
+impl AppConfig {
+    pub fn parse(text: &str) -> Self {
+        let mut config = Self::default();
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once('=') else { continue };
+            if key.trim() == "fov_degrees" {
+                // Clamp to the slider range so a corrupt file can't set an absurd FOV
+                config.fov_degrees = value.trim().parse::<f32>().ok()
+                    .filter(|fov| fov.is_finite())
+                    .map(|fov| fov.clamp(FOV_MIN_DEGREES, FOV_MAX_DEGREES));
+            }
+        }
+        config
+    }
+    
+    pub fn to_config_string(&self) -> String {
+        let mut text = String::new();
+        if let Some(fov) = self.fov_degrees {
+            let _ = writeln!(text, "fov_degrees = {}", fov);
+        }
+        text
+    }
+}
+
+/// Startup system to restore persisted settings before the camera is spawned
+pub fn load_app_config(mut projection_state: ResMut<CameraProjectionState>) {
+    // A missing file just means first run, keep the defaults
+    let Ok(text) = std::fs::read_to_string(CONFIG_FILE_PATH) else { return };
+    let config = AppConfig::parse(&text);
+    if let Some(fov) = config.fov_degrees {
+        projection_state.last_perspective_fov = fov.to_radians();
+    }
+}
+
+/// System to write settings back to the config file when they change
+/// Waits for the mouse button to be released so a slider drag is saved once
+pub fn save_app_config(
+    projection_state: Res<CameraProjectionState>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    mut notifications: ResMut<Notifications>,
+    mut last_saved: Local<Option<AppConfig>>,
+) {
+    let config = AppConfig {
+        fov_degrees: Some(projection_state.last_perspective_fov.to_degrees().round()),
+    };
+    
+    // The first run only records the loaded state, nothing has changed yet
+    let Some(saved) = last_saved.as_ref() else {
+        *last_saved = Some(config);
+        return;
+    };
+    if *saved == config || mouse_button_input.pressed(MouseButton::Left) {
+        return;
+    }
+    
+    if let Err(error) = std::fs::write(CONFIG_FILE_PATH, config.to_config_string()) {
+        warn!("Failed to save config to {}: {}", CONFIG_FILE_PATH, error);
+        notify(&mut notifications, format!("Failed to save settings: {}", error));
+    }
+    *last_saved = Some(config);
+}
+
+/// Format trails as CSV with one row per recorded point: particle,t,x,y,z
+/// Particles are identified by their entity index; an empty input yields just the header
+pub fn trails_to_csv<'a>(trails: impl IntoIterator<Item = (Entity, &'a TrajectoryTrail)>) -> String {
+    let mut csv = String::from("particle,t,x,y,z\n");
+    for (entity, trail) in trails {
+        for (t, position) in trail.points.iter() {
+            let _ = writeln!(csv, "{},{},{},{},{}", entity.index(), t, position.x, position.y, position.z);
+        }
+    }
+    csv
+}
+
+/// System to export the recorded trails of the selected particles to a combined CSV file
+pub fn export_trajectory_csv(
+    mut io_state: ResMut<IoState>,
+    mut notifications: ResMut<Notifications>,
+    selection_state: Res<ParticleSelectionState>,
+    trail_query: Query<&TrajectoryTrail>,
+) {
+    if !io_state.export_trails_requested {
+        return;
+    }
+    io_state.export_trails_requested = false;
+    
+    // Sorted so repeated exports list particles in the same order
+    let selected = selection_state.sorted();
+    // Selected particles without a recorded trail are left out (and not counted)
+    let trails: Vec<(Entity, &TrajectoryTrail)> = selected.iter()
+        .filter_map(|entity| trail_query.get(*entity).ok().map(|trail| (*entity, trail)))
+        .collect();
+    let trail_count = trails.len();
+    let csv = trails_to_csv(trails);
+    
+    let path = io_state.trail_export_path.clone();
+    io_state.status = match std::fs::write(&path, csv) {
+        Ok(()) => {
+            info!("Exported trajectories of {} particles to {}", trail_count, path);
+            format!("Exported {} trails to {}", trail_count, path)
+        }
+        Err(error) => {
+            warn!("Failed to export trajectories to {}: {}", path, error);
+            format!("Export failed: {}", error)
+        }
+    };
+    notify(&mut notifications, io_state.status.clone());
+}
+
+/// One particle in a JSON scene file: `{"x": 0.0, "y": 1.0, "z": 0.0, "selected": true, "color": "#ff8800"}`
+/// `selected` and `color` are optional (unselected, white); the color is an sRGB hex string
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ParticleRecord {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    #[serde(default)]
+    pub selected: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Serialize a scene as a JSON array of particle records
+/// f32 values are written in their shortest exact form, so importing restores the same positions
+pub fn particles_to_json(records: &[ParticleRecord]) -> String {
+    // Serializing plain records cannot fail
+    serde_json::to_string_pretty(records).unwrap_or_default()
+}
+
+/// Parse a JSON array of particle records
+pub fn particles_from_json(text: &str) -> Result<Vec<ParticleRecord>, serde_json::Error> {
+    serde_json::from_str(text)
+}
+
+/// System to export every particle's position, selection and base color to the JSON scene file
+pub fn export_scene_json(
+    mut io_state: ResMut<IoState>,
+    mut notifications: ResMut<Notifications>,
+    particle_positions: Res<ParticlePositions>,
+    selection_state: Res<ParticleSelectionState>,
+    materials: Res<Assets<StandardMaterial>>,
+    particle_query: Query<&ParticleBaseColor, With<Particle>>,
+) {
+    if !io_state.export_scene_requested {
+        return;
+    }
+    io_state.export_scene_requested = false;
+    
+    // Sorted so repeated exports list particles in the same order
+    let mut entities: Vec<Entity> = particle_positions.current_positions.keys().copied().collect();
+    entities.sort_unstable();
+    let records: Vec<ParticleRecord> = entities.iter().map(|entity| {
+        let position = particle_positions.current_positions[entity];
+        let color = particle_query.get(*entity).ok()
+            .and_then(|base_color| materials.get(&base_color.0))
+            .map(|material| material.base_color.to_srgba().to_hex());
+        ParticleRecord {
+            x: position.x,
+            y: position.y,
+            z: position.z,
+            selected: selection_state.selected_particles.contains(entity),
+            color,
+        }
+    }).collect();
+    
+    let path = io_state.scene_path.clone();
+    io_state.status = match std::fs::write(&path, particles_to_json(&records)) {
+        Ok(()) => {
+            info!("Exported {} particles to {}", records.len(), path);
+            format!("Exported {} particles to {}", records.len(), path)
+        }
+        Err(error) => {
+            warn!("Failed to export scene to {}: {}", path, error);
+            format!("Export failed: {}", error)
+        }
+    };
+    notify(&mut notifications, io_state.status.clone());
+}
+
+/// System to add the particles of the JSON scene file to the scene
+/// Uses the regular spawn path (current shape and radius), stays under the particle cap and
+/// adds particles marked as selected to the selection; unreadable or malformed files are
+/// reported and leave the scene unchanged
+pub fn import_scene_json(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut io_state: ResMut<IoState>,
+    mut notifications: ResMut<Notifications>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+    mut max_particles: ResMut<MaxParticles>,
+    pending_spawns: Res<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
+) {
+    if !io_state.import_scene_requested {
+        return;
+    }
+    io_state.import_scene_requested = false;
+    
+    let path = io_state.scene_path.clone();
+    let records = match std::fs::read_to_string(&path) {
+        Ok(text) => particles_from_json(&text).map_err(|error| format!("Malformed scene file: {}", error)),
+        Err(error) => Err(format!("Import failed: {}", error)),
+    };
+    let records = match records {
+        Ok(records) => records,
+        Err(message) => {
+            warn!("Failed to import scene from {}: {}", path, message);
+            io_state.status = message;
+            notify(&mut notifications, io_state.status.clone());
+            return;
+        }
+    };
+    
+    let total = particle_positions.particle_count() + pending_spawns.positions.len();
+    let count = records.len().min(max_particles.remaining(total));
+    max_particles.clamped = count < records.len();
+    
+    let shape = spawn_settings.shape_state.shape;
+    let mesh = spawn_settings.shape_state.mesh(shape, &mut meshes);
+    // One material per distinct color; missing or invalid colors use white
+    let mut color_materials: std::collections::HashMap<Option<String>, Handle<StandardMaterial>> = std::collections::HashMap::new();
+    let mut added = Vec::new();
+    for record in records.iter().take(count) {
+        let material = color_materials.entry(record.color.clone()).or_insert_with(|| {
+            let color = record.color.as_deref()
+                .and_then(|hex| Srgba::hex(hex).ok())
+                .map(Color::from)
+                .unwrap_or(COLOR_WHITE);
+            materials.add(color)
+        }).clone();
+        let entity = spawn_single_particle(
+            &mut commands,
+            &mut particle_positions,
+            Vec3::new(record.x, record.y, record.z),
+            shape,
+            &mesh,
+            &material,
+            &spawn_settings,
+        );
+        if record.selected {
+            commands.entity(entity).insert(Selected);
+            selection_state.selected_particles.insert(entity);
+            added.push(entity);
+        }
+    }
+    if !added.is_empty() {
+        selection_changed.write(SelectionChanged { added, removed: Vec::new() });
+    }
+    
+    io_state.status = if count < records.len() {
+        format!("Loaded {} of {} particles from {} (particle cap)", count, records.len(), path)
+    } else {
+        format!("Loaded {} particles from {}", count, path)
+    };
+    info!("{}", io_state.status);
+    notify(&mut notifications, io_state.status.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn particle_records_round_trip_exactly() {
+        let records = vec![
+            ParticleRecord { x: 0.1, y: 1.0 / 3.0, z: -2.7182817, selected: true, color: Some("#ff8800".to_string()) },
+            ParticleRecord { x: 1e-7, y: 1.5, z: 123456.79, selected: false, color: None },
+        ];
+        
+        let parsed = particles_from_json(&particles_to_json(&records)).expect("exported scene parses");
+        
+        // Exact equality: positions come back bit for bit
+        assert_eq!(parsed, records);
+    }
+    
+    #[test]
+    fn omitted_selected_and_color_use_the_defaults() {
+        let parsed = particles_from_json(r#"[{"x": 1.0, "y": 2.0, "z": 3.0}]"#).expect("minimal record parses");
+        
+        assert_eq!(parsed, vec![ParticleRecord { x: 1.0, y: 2.0, z: 3.0, selected: false, color: None }]);
+    }
+    
+    #[test]
+    fn malformed_scenes_are_errors() {
+        for text in ["", "{", r#"{"x": 1.0, "y": 2.0, "z": 3.0}"#, r#"[{"x": 1.0, "y": 2.0}]"#, r#"[{"x": "one", "y": 2.0, "z": 3.0}]"#] {
+            assert!(particles_from_json(text).is_err(), "accepted {text:?}");
+        }
+    }
+}
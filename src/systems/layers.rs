@@ -0,0 +1,62 @@
+// systems/layers.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{LayerEntry, LayerState, ParticleLayer, ParticleSelectionState, Particle};
+
+/// System to handle layer creation, selection assignment and visibility changes
+pub fn update_particle_layers(
+    mut commands: Commands,
+    mut layer_state: ResMut<LayerState>,
+    selection_state: Res<ParticleSelectionState>,
+    mut particle_query: Query<(&mut Visibility, Option<&ParticleLayer>), With<Particle>>,
+) {
+    // Create a new layer from the name field (ignore empty and duplicate names)
+    if layer_state.create_requested {
+        layer_state.create_requested = false;
+        
+        let name = layer_state.new_layer_name.trim().to_string();
+        if !name.is_empty() && !layer_state.layers.iter().any(|layer| layer.name == name) {
+            layer_state.layers.push(LayerEntry {
+                name,
+                visible: true,
+                members: std::collections::HashSet::new(),
+            });
+            layer_state.new_layer_name.clear();
+        }
+    }
+    
+    // Assign the current selection to a layer (a particle belongs to one layer at a time)
+    if let Some(index) = layer_state.assign_selection_requested.take()
+        && index < layer_state.layers.len()
+    {
+        for entity in selection_state.selected_particles.iter() {
+            let Ok((_, previous_layer)) = particle_query.get(*entity) else { continue };
+            
+            // Leave the previous layer before joining the new one
+            if let Some(previous_layer) = previous_layer
+                && let Some(layer) = layer_state.layers.iter_mut().find(|layer| layer.name == previous_layer.0)
+            {
+                layer.members.remove(entity);
+            }
+            layer_state.layers[index].members.insert(*entity);
+            commands.entity(*entity).insert(ParticleLayer(layer_state.layers[index].name.clone()));
+        }
+        // Newly assigned particles take the visibility of their layer
+        layer_state.visibility_changed = true;
+    }
+    
+    // Apply layer visibility to member particles
+    if layer_state.visibility_changed {
+        layer_state.visibility_changed = false;
+        
+        for layer in layer_state.layers.iter() {
+            let visibility = if layer.visible { Visibility::Visible } else { Visibility::Hidden };
+            for entity in layer.members.iter() {
+                if let Ok((mut particle_visibility, _)) = particle_query.get_mut(*entity) {
+                    *particle_visibility = visibility;
+                }
+            }
+        }
+    }
+}
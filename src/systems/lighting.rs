@@ -0,0 +1,41 @@
+// systems/lighting.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::LightingState;
+
+/// System to apply lighting settings to the directional lights and the global ambient light
+pub fn apply_lighting_settings(
+    lighting_state: Res<LightingState>,
+    mut light_query: Query<(&mut DirectionalLight, &mut Transform)>,
+    mut ambient_light: ResMut<GlobalAmbientLight>,
+) {
+    if !lighting_state.is_changed() {
+        return;
+    }
+    
+    // Front light direction from yaw/pitch; back light points the opposite way
+    let front_rotation = Quat::from_euler(
+        bevy::math::EulerRot::YXZ,
+        lighting_state.light_yaw.to_radians(),
+        lighting_state.light_pitch.to_radians(),
+        0.0,
+    );
+    let back_rotation = front_rotation * Quat::from_rotation_y(std::f32::consts::PI);
+    
+    if let Some(entity) = lighting_state.front_light
+        && let Ok((mut light, mut transform)) = light_query.get_mut(entity)
+    {
+        light.illuminance = lighting_state.front_illuminance;
+        transform.rotation = front_rotation;
+    }
+    
+    if let Some(entity) = lighting_state.back_light
+        && let Ok((mut light, mut transform)) = light_query.get_mut(entity)
+    {
+        light.illuminance = lighting_state.back_illuminance;
+        transform.rotation = back_rotation;
+    }
+    
+    ambient_light.brightness = lighting_state.ambient_brightness;
+}
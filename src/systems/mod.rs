@@ -12,65 +12,136 @@ pub mod grid;
 pub mod particle_creation;
 pub mod selection_bounds;
 pub mod selection_transform;
+pub mod layers;
+pub mod physics;
+pub mod lighting;
+pub mod render_settings;
+pub mod motion;
+pub mod flocking;
+pub mod io;
+pub mod emitter;
+pub mod colormap;
+pub mod proximity;
+pub mod notifications;
+pub mod playback;
+#[cfg(test)]
+pub mod test_harness;
 
-pub use camera::reset_viewport_constrained_camera_after_view_change;
+pub use camera::{smooth_camera_view_change, reset_viewport_constrained_camera_after_view_change};
 pub use particles::*;
 pub use selection::*;
-pub use egui_ui::egui_controls_ui;
+pub use egui_ui::{egui_controls_ui, handle_panel_shortcuts};
 pub use mouse::*;
-pub use grid::update_grid_dimensions;
+pub use grid::{draw_axes, draw_grid, follow_selection_with_grid, update_ground_plane, update_axis_labels, draw_orientation_gizmo};
 pub use particle_creation::*;
-pub use selection_bounds::update_selection_bounding_box;
-pub use selection_transform::{update_selection_original_positions, update_selection_transform};
+pub use selection_bounds::{update_selection_stats, update_selection_bounding_box, draw_selection_centroid};
+pub use layers::update_particle_layers;
+pub use physics::{draw_velocity_arrows, apply_attractor_force, draw_attractor};
+pub use lighting::apply_lighting_settings;
+pub use render_settings::{apply_render_settings, apply_particle_glow};
+pub use io::{export_trajectory_csv, export_scene_json, import_scene_json, load_app_config, save_app_config};
+pub use proximity::draw_proximity_lines;
+pub use notifications::{notify, expire_notifications, notify_particle_cap};
+pub use playback::{live_simulation, handle_playback_requests, record_playback_frame, apply_playback_frame};
+pub use colormap::apply_color_by_field;
+pub use emitter::{emit_particles, move_emitted_particles, draw_emitter};
+pub use flocking::{update_spatial_grid, update_neighbor_stats, handle_flock_assignment, animate_flocking};
+pub use motion::{handle_wave_toggle, animate_wave_particles, animate_jitter_particles};
+pub use selection_transform::{update_selection_original_positions, update_selection_transform, reset_selection_transform, handle_selection_flatten, handle_selection_scatter, handle_selection_nudge, handle_exact_position, handle_apply_selection_transform};
+
+/// Rotation center used by Motion1: the explicit pivot, or the XZ of the group offset on the
+/// ground plane when `use_group_offset` is set
+/// Shared by the animation, the trajectory visualization and the pivot marker so all describe the same orbit
+pub fn motion1_rotation_center(motion1_state: &crate::components::Motion1State, group_state: &crate::components::ParticleGroupState) -> Vec3 {
+    if motion1_state.use_group_offset {
+        Vec3::new(group_state.offset.x, 0.0, group_state.offset.z)
+    } else {
+        motion1_state.rotation_center
+    }
+}
+
+/// System to mark the Motion1 pivot while the motion is active
+pub fn draw_motion1_center(
+    mut gizmos: Gizmos,
+    motion1_state: Res<crate::components::Motion1State>,
+    group_state: Res<crate::components::ParticleGroupState>,
+) {
+    if !motion1_state.is_active {
+        return;
+    }
+    
+    let center = motion1_rotation_center(&motion1_state, &group_state);
+    let size = crate::constants::MOTION1_CENTER_MARKER_SIZE;
+    let color = crate::constants::MOTION1_CENTER_COLOR;
+    gizmos.sphere(Isometry3d::from_translation(center), size, color);
+    // Short stub along the orbit axis shows the orbit orientation
+    let axis = motion1_state.axis();
+    gizmos.line(center - axis * size * 3.0, center + axis * size * 3.0, color);
+}
 
 pub fn animate_motion1_particles(
+    mut commands: Commands,
     time: Res<Time>,
     motion1_state: Res<crate::components::Motion1State>,
-    mut particle_query: Query<(Entity, &mut Transform), (With<crate::components::Particle>, With<crate::components::InMotion>)>,
+    mut particle_query: Query<(Entity, &mut Transform, Option<&crate::components::OrbitPhase>, Has<crate::components::SoloMotion>), (With<crate::components::Particle>, With<crate::components::InMotion>, Without<crate::components::Locked>, Without<crate::components::WaveMotion>)>,
     mut particle_positions: ResMut<crate::components::ParticlePositions>,
+    mut transform_state: ResMut<crate::components::SelectionTransformState>,
     group_state: Res<crate::components::ParticleGroupState>,
 ) {
-    if !motion1_state.is_active {
+    // Hover-driven solo motion runs without the persistent motion being active
+    if !motion1_state.is_active && !motion1_state.solo_on_hover {
         return;
     }
     
     let delta_time = time.delta_secs();
     let rotation_delta = motion1_state.rotation_speed * delta_time;
     
-    // Calculate rotation center accounting for group offset
-    // Motion rotates around the group offset center, not world origin
-    let rotation_center = Vec3::new(group_state.offset.x, 0.0, group_state.offset.z);
+    // Explicit pivot, or the group offset center when following the group
+    let rotation_center = motion1_rotation_center(&motion1_state, &group_state);
+    
+    // Orbit axis through the rotation center (Y by default, giving the flat XZ orbit)
+    // Negative angle keeps the original XZ direction (increasing atan2(z, x)) when the axis is Y
+    let axis = motion1_state.axis();
     
     // Apply motion only to particles with InMotion component
-    for (entity, mut transform) in particle_query.iter_mut() {
+    // (locked particles stay pinned, wave motion takes precedence)
+    for (entity, mut transform, phase, is_solo) in particle_query.iter_mut() {
+        if !motion1_state.is_active && !is_solo {
+            continue;
+        }
+        
         let current_pos = transform.translation;
         
-        // Calculate position relative to rotation center
+        // Split position relative to rotation center into the part along the axis
+        // and the radial part perpendicular to it (the orbit radius)
         let relative_pos = current_pos - rotation_center;
-        let xz_relative = Vec3::new(relative_pos.x, 0.0, relative_pos.z);
-        let radius = xz_relative.length();
+        let axial = axis * relative_pos.dot(axis);
+        let radial = relative_pos - axial;
+        let radius = radial.length();
         
-        if radius > 0.001 {
-            // Calculate current angle in XZ plane relative to rotation center
-            let current_angle = xz_relative.z.atan2(xz_relative.x);
-            
-            // Rotate clockwise (increase angle)
-            let new_angle = current_angle + rotation_delta;
-            
-            // Calculate new XZ position maintaining radius, relative to rotation center
-            let new_x_relative = radius * new_angle.cos();
-            let new_z_relative = radius * new_angle.sin();
-            
-            // Convert back to world coordinates
-            let new_x = rotation_center.x + new_x_relative;
-            let new_z = rotation_center.z + new_z_relative;
-            
-            // Update position maintaining Y height
-            transform.translation = Vec3::new(new_x, current_pos.y, new_z);
-            
-            // Update global position state
-            particle_positions.current_positions.insert(entity, transform.translation);
+        // A particle on the axis has no well-defined orbit angle, leave it untouched
+        // (no snapping to an arbitrary direction from float noise)
+        if radius <= crate::constants::MOTION1_MIN_RADIUS {
+            continue;
         }
+        
+        // A pending starting phase is used up on the particle's first orbit step
+        // (it waits while the particle is locked, in wave motion or on the axis)
+        let phase_angle = phase.map(|phase| {
+            commands.entity(entity).remove::<crate::components::OrbitPhase>();
+            phase.0
+        }).unwrap_or(0.0);
+        
+        // Rotate the radial part around the axis, keeping radius and axial height
+        let new_radial = Quat::from_axis_angle(axis, -(rotation_delta + phase_angle)) * radial;
+        
+        // Convert back to world coordinates
+        let new_position = rotation_center + axial + new_radial;
+        selection_transform::shift_selection_original(&mut transform_state, entity, new_position - current_pos);
+        transform.translation = new_position;
+        
+        // Update global position state
+        particle_positions.current_positions.insert(entity, transform.translation);
     }
 }
 
@@ -81,34 +152,58 @@ pub fn update_trajectory_visualization(
     trajectory_state: Res<crate::components::TrajectoryState>,
     selection_state: Res<crate::components::ParticleSelectionState>,
     particle_query: Query<&Transform, With<crate::components::Particle>>,
-    trajectory_query: Query<(Entity, &crate::components::TrajectoryCircle)>,
+    trajectory_query: Query<(Entity, &crate::components::TrajectoryCircle, &MeshMaterial3d<StandardMaterial>)>,
+    group_state: Res<crate::components::ParticleGroupState>,
+    motion1_state: Res<crate::components::Motion1State>,
+    trajectory_style: Res<crate::components::TrajectoryStyle>,
+    mut last_orbit: Local<Option<(Vec3, Vec3, f32)>>,
 ) {
-    use crate::constants::TRAJECTORY_CIRCLE_THICKNESS;
-    use crate::constants::TRAJECTORY_COLOR;
+    // Trajectories are drawn around the same center and axis Motion1 rotates around
+    let rotation_center = motion1_rotation_center(&motion1_state, &group_state);
+    let axis = motion1_state.axis();
+    // Torus lies in the XZ plane by default, rotate Y onto the orbit axis to tilt it
+    let ring_rotation = Quat::from_rotation_arc(Vec3::Y, axis);
+    
+    // Rebuild existing circles when the orbit center, axis or ring thickness changes
+    let orbit = (rotation_center, axis, trajectory_style.thickness);
+    let orbit_changed = last_orbit.is_some_and(|last| last != orbit);
+    *last_orbit = Some(orbit);
     
-    if trajectory_state.is_visible {
+    // Color changes only need the existing materials updated
+    if trajectory_style.is_changed() {
+        for (_, _, material) in trajectory_query.iter() {
+            if let Some(material) = materials.get_mut(&material.0) {
+                material.base_color = trajectory_style.color;
+            }
+        }
+    }
+    
+    if trajectory_state.is_visible && !orbit_changed {
         // Spawn trajectory circles for selected particles that don't have one yet
         for particle_entity in &selection_state.selected_particles {
             // Check if trajectory already exists for this particle
-            let has_trajectory = trajectory_query.iter().any(|(_, circle)| circle.particle_entity == *particle_entity);
+            let has_trajectory = trajectory_query.iter().any(|(_, circle, _)| circle.particle_entity == *particle_entity);
             
             if !has_trajectory {
                 if let Ok(transform) = particle_query.get(*particle_entity) {
                     let pos = transform.translation;
                     
-                    // Calculate radius in XZ plane
-                    let xz_pos = Vec3::new(pos.x, 0.0, pos.z);
-                    let radius = xz_pos.length().max(0.1); // Minimum radius to avoid zero-size circles
+                    // Radius is the distance from the axis line through the rotation center
+                    // Ring center is the particle's projection onto that axis line
+                    let relative_pos = pos - rotation_center;
+                    let axial = axis * relative_pos.dot(axis);
+                    let radius = (relative_pos - axial).length().max(0.1); // Minimum radius to avoid zero-size circles
+                    let ring_center = rotation_center + axial;
                     
                     // Create a high-resolution torus (ring) for the trajectory circle
                     // Bevy's Torus primitive provides good quality by default
                     let torus = bevy::prelude::Torus {
                         major_radius: radius,
-                        minor_radius: TRAJECTORY_CIRCLE_THICKNESS,
+                        minor_radius: trajectory_style.thickness,
                     };
                     
                     let trajectory_material = materials.add(StandardMaterial {
-                        base_color: TRAJECTORY_COLOR,
+                        base_color: trajectory_style.color,
                         unlit: true, // Make it visible regardless of lighting
                         ..default()
                     });
@@ -116,7 +211,7 @@ pub fn update_trajectory_visualization(
                     commands.spawn((
                         Mesh3d(meshes.add(torus)),
                         MeshMaterial3d(trajectory_material),
-                        Transform::from_translation(Vec3::new(0.0, pos.y, 0.0)),
+                        Transform::from_translation(ring_center).with_rotation(ring_rotation),
                         crate::components::TrajectoryCircle {
                             particle_entity: *particle_entity,
                         },
@@ -126,17 +221,290 @@ pub fn update_trajectory_visualization(
         }
         
         // Remove trajectory circles for particles that are no longer selected
-        for (trajectory_entity, circle) in trajectory_query.iter() {
+        for (trajectory_entity, circle, _) in trajectory_query.iter() {
             if !selection_state.selected_particles.contains(&circle.particle_entity) {
                 commands.entity(trajectory_entity).despawn();
             }
         }
     } else {
-        // Remove all trajectory circles when hidden
-        for (trajectory_entity, _) in trajectory_query.iter() {
+        // Remove all trajectory circles when hidden (or stale after an orbit change)
+        for (trajectory_entity, _, _) in trajectory_query.iter() {
             commands.entity(trajectory_entity).despawn();
         }
     }
 }
 
 
+
+/// System to record the paths of selected particles while trajectories are shown
+/// Samples every `sample_interval` frames and keeps at most `max_points` per trail
+/// With short motion trails on and trajectories hidden, selected particles in Motion1 keep
+/// only the last MOTION_TRAIL_POINTS frames, and lose the trail once their motion stops
+pub fn record_trajectory_trails(
+    mut commands: Commands,
+    time: Res<Time>,
+    trajectory_state: Res<crate::components::TrajectoryState>,
+    selection_state: Res<crate::components::ParticleSelectionState>,
+    mut particle_query: Query<(Entity, &Transform, Has<crate::components::InMotion>, Option<&mut crate::components::TrajectoryTrail>), With<crate::components::Particle>>,
+    mut frame_counter: Local<u32>,
+) {
+    // Trails only exist for selected particles while trajectories are visible (or they are in motion, for short trails)
+    let keeps_trail = |entity: Entity, in_motion: bool| {
+        selection_state.selected_particles.contains(&entity)
+            && (trajectory_state.is_visible || (trajectory_state.motion_trails && in_motion))
+    };
+    for (entity, _, in_motion, trail) in particle_query.iter() {
+        if trail.is_some() && !keeps_trail(entity, in_motion) {
+            commands.entity(entity).remove::<crate::components::TrajectoryTrail>();
+        }
+    }
+    if !trajectory_state.is_visible && !trajectory_state.motion_trails {
+        return;
+    }
+    
+    *frame_counter = frame_counter.wrapping_add(1);
+    let (sample_now, max_points) = if trajectory_state.is_visible {
+        (frame_counter.is_multiple_of(trajectory_state.sample_interval.max(1)), trajectory_state.max_points.max(1))
+    } else {
+        (true, crate::constants::MOTION_TRAIL_POINTS)
+    };
+    
+    for entity in selection_state.selected_particles.iter() {
+        let Ok((_, transform, in_motion, trail)) = particle_query.get_mut(*entity) else { continue };
+        if !keeps_trail(*entity, in_motion) {
+            continue;
+        }
+        let point = (time.elapsed_secs(), transform.translation);
+        
+        match trail {
+            Some(mut trail) => {
+                if sample_now {
+                    trail.points.push_back(point);
+                }
+                // Also applies a lowered max length to existing buffers
+                while trail.points.len() > max_points {
+                    trail.points.pop_front();
+                }
+            }
+            None => {
+                let mut trail = crate::components::TrajectoryTrail::default();
+                trail.points.push_back(point);
+                commands.entity(*entity).insert(trail);
+            }
+        }
+    }
+}
+
+/// System to draw recorded trails as gizmo line strips
+/// Short motion trails fade out toward their oldest point
+pub fn draw_trajectory_trails(
+    mut gizmos: Gizmos,
+    trajectory_state: Res<crate::components::TrajectoryState>,
+    trajectory_style: Res<crate::components::TrajectoryStyle>,
+    trail_query: Query<&crate::components::TrajectoryTrail>,
+) {
+    let color = trajectory_style.color;
+    for trail in trail_query.iter() {
+        if trail.points.len() < 2 {
+            continue;
+        }
+        if trajectory_state.is_visible {
+            gizmos.linestrip(trail.points.iter().map(|(_, position)| *position), color);
+        } else {
+            let count = trail.points.len() as f32;
+            gizmos.linestrip_gradient(trail.points.iter().enumerate().map(|(index, (_, position))| {
+                (*position, color.with_alpha(color.alpha() * (index + 1) as f32 / count))
+            }));
+        }
+    }
+}
+
+/// System to freeze the current trajectory circles and trails into static snapshot entities,
+/// or to clear all snapshots
+/// Snapshots own their material and are independent of the trajectory visibility toggle
+pub fn handle_trajectory_snapshots(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut trajectory_state: ResMut<crate::components::TrajectoryState>,
+    trajectory_style: Res<crate::components::TrajectoryStyle>,
+    circle_query: Query<(&Mesh3d, &Transform), With<crate::components::TrajectoryCircle>>,
+    trail_query: Query<&crate::components::TrajectoryTrail>,
+    frozen_query: Query<Entity, With<crate::components::FrozenTrajectory>>,
+) {
+    if trajectory_state.clear_snapshots_requested {
+        trajectory_state.clear_snapshots_requested = false;
+        for entity in frozen_query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+    
+    if !trajectory_state.snapshot_requested {
+        return;
+    }
+    trajectory_state.snapshot_requested = false;
+    
+    // One material per snapshot, so later style changes leave it as it was
+    let snapshot_material = materials.add(StandardMaterial {
+        base_color: trajectory_style.color.with_alpha(crate::constants::FROZEN_TRAJECTORY_ALPHA),
+        alpha_mode: AlphaMode::Blend,
+        unlit: true,
+        ..default()
+    });
+    
+    // Circles share the live ring mesh (it is never modified, only replaced)
+    for (mesh, transform) in circle_query.iter() {
+        commands.spawn((
+            mesh.clone(),
+            MeshMaterial3d(snapshot_material.clone()),
+            *transform,
+            crate::components::FrozenTrajectory,
+        ));
+    }
+    
+    // Trails become line strip meshes
+    for trail in trail_query.iter() {
+        if trail.points.len() < 2 {
+            continue;
+        }
+        let positions: Vec<Vec3> = trail.points.iter().map(|(_, position)| *position).collect();
+        let mesh = Mesh::new(bevy::mesh::PrimitiveTopology::LineStrip, bevy::asset::RenderAssetUsages::default())
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        commands.spawn((
+            Mesh3d(meshes.add(mesh)),
+            MeshMaterial3d(snapshot_material.clone()),
+            Transform::IDENTITY,
+            crate::components::FrozenTrajectory,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{InMotion, Motion1State};
+    use crate::systems::test_harness::*;
+    
+    #[test]
+    fn motion1_orbits_in_motion_particles_around_the_rotation_center() {
+        let mut app = test_app();
+        app.add_systems(Update, animate_motion1_particles);
+        {
+            let mut motion1_state = app.world_mut().resource_mut::<Motion1State>();
+            motion1_state.is_active = true;
+            motion1_state.use_group_offset = false;
+            motion1_state.rotation_center = Vec3::new(1.0, 0.0, 1.0);
+        }
+        let particles = spawn_particles(&mut app, &[Vec3::new(3.0, 1.5, 1.0), Vec3::new(-2.0, 1.0, 0.0)]);
+        app.world_mut().entity_mut(particles[0]).insert(InMotion);
+        
+        run_updates(&mut app, 30);
+        
+        // Radius and height are kept, the angle advances by rotation_speed * time
+        let angle = 30.0 * FRAME_SECONDS;
+        let expected = Vec3::new(1.0, 1.5, 1.0) + Quat::from_rotation_y(-angle) * Vec3::new(2.0, 0.0, 0.0);
+        assert_near(position(&app, particles[0]), expected);
+        assert_near(position(&app, particles[1]), Vec3::new(-2.0, 1.0, 0.0));
+    }
+    
+    #[test]
+    fn trajectory_circle_matches_the_orbit_with_a_group_offset() {
+        use bevy::camera::primitives::MeshAabb;
+        use crate::components::{ParticleGroupState, TrajectoryCircle, TrajectoryState, TrajectoryStyle};
+        
+        let mut app = test_app();
+        app.add_systems(Update, (update_trajectory_visualization, animate_motion1_particles).chain());
+        app.world_mut().resource_mut::<ParticleGroupState>().offset = Vec3::new(3.0, 0.5, -2.0);
+        app.world_mut().resource_mut::<Motion1State>().is_active = true;
+        app.world_mut().resource_mut::<TrajectoryState>().is_visible = true;
+        let particles = spawn_particles(&mut app, &[Vec3::new(5.0, 1.5, -2.0)]);
+        app.world_mut().entity_mut(particles[0]).insert(InMotion);
+        set_selection(&mut app, &particles);
+        
+        run_updates(&mut app, 20);
+        
+        // Ring centered on the group offset's orbit axis at the particle's height
+        let mut circles = app.world_mut().query::<(&Transform, &Mesh3d, &TrajectoryCircle)>();
+        let (ring_transform, mesh, _) = circles.single(app.world()).expect("one trajectory circle");
+        assert_near(ring_transform.translation, Vec3::new(3.0, 1.5, -2.0));
+        
+        // Drawn radius (torus extent minus the tube) equals the orbit radius the particle keeps
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        let aabb = meshes.get(&mesh.0).and_then(|mesh| mesh.compute_aabb()).expect("torus mesh");
+        let drawn_radius = aabb.half_extents.x - app.world().resource::<TrajectoryStyle>().thickness;
+        let orbit_offset = position(&app, particles[0]) - ring_transform.translation;
+        assert!((drawn_radius - 2.0).abs() < 1e-3, "drawn radius {drawn_radius}");
+        assert!((orbit_offset.length() - drawn_radius).abs() < 1e-3, "orbit radius {}", orbit_offset.length());
+        assert!(orbit_offset.y.abs() < 1e-4);
+    }
+    
+    #[test]
+    fn motion1_leaves_particles_on_the_rotation_axis_unchanged() {
+        let mut app = test_app();
+        app.add_systems(Update, animate_motion1_particles);
+        {
+            let mut motion1_state = app.world_mut().resource_mut::<Motion1State>();
+            motion1_state.is_active = true;
+            motion1_state.use_group_offset = false;
+            motion1_state.rotation_center = Vec3::new(1.0, 1.5, 1.0);
+        }
+        // Exactly at the center, and on the axis above it
+        let start = [Vec3::new(1.0, 1.5, 1.0), Vec3::new(1.0, 2.0, 1.0)];
+        let particles = spawn_particles(&mut app, &start);
+        for entity in particles.iter() {
+            app.world_mut().entity_mut(*entity).insert(InMotion);
+        }
+        set_selection(&mut app, &particles);
+        
+        run_updates(&mut app, 10);
+        
+        for (entity, start) in particles.iter().zip(start) {
+            assert_eq!(position(&app, *entity), start);
+        }
+    }
+    
+    #[test]
+    fn motion1_keeps_orbiting_a_selected_particle() {
+        use crate::systems::selection_transform::{update_selection_original_positions, update_selection_transform};
+        
+        let mut app = test_app();
+        app.add_systems(Update, (update_selection_original_positions, update_selection_transform, animate_motion1_particles).chain());
+        {
+            let mut motion1_state = app.world_mut().resource_mut::<Motion1State>();
+            motion1_state.is_active = true;
+            motion1_state.use_group_offset = false;
+            motion1_state.rotation_center = Vec3::new(1.0, 0.0, 1.0);
+        }
+        let particles = spawn_particles(&mut app, &[Vec3::new(3.0, 1.5, 1.0)]);
+        app.world_mut().entity_mut(particles[0]).insert(InMotion);
+        set_selection(&mut app, &particles);
+        
+        run_updates(&mut app, 30);
+        
+        // The selection transform rewrites from the originals each frame, so the orbit only advances if they follow it
+        let angle = 30.0 * FRAME_SECONDS;
+        let expected = Vec3::new(1.0, 1.5, 1.0) + Quat::from_rotation_y(-angle) * Vec3::new(2.0, 0.0, 0.0);
+        assert_near(position(&app, particles[0]), expected);
+    }
+    
+    #[test]
+    fn motion1_keeps_the_orbit_phase_of_a_particle_on_the_axis() {
+        use crate::components::OrbitPhase;
+        
+        let mut app = test_app();
+        app.add_systems(Update, animate_motion1_particles);
+        {
+            let mut motion1_state = app.world_mut().resource_mut::<Motion1State>();
+            motion1_state.is_active = true;
+            motion1_state.use_group_offset = false;
+            motion1_state.rotation_center = Vec3::new(1.0, 1.5, 1.0);
+        }
+        let particles = spawn_particles(&mut app, &[Vec3::new(1.0, 2.0, 1.0)]);
+        app.world_mut().entity_mut(particles[0]).insert((InMotion, OrbitPhase(1.0)));
+        
+        run_updates(&mut app, 5);
+        
+        // No orbit step was taken, so the phase is still pending
+        assert!(app.world().get::<OrbitPhase>(particles[0]).is_some());
+    }
+}
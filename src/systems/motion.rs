@@ -0,0 +1,112 @@
+// systems/motion.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Particle, ParticlePositions, ParticleSelectionState, WaveMotion, WaveMotionState, JitterMotionState, ParticleRng, ParticleBoundsState, ParticleGroupState, SelectionTransformState, Locked};
+use crate::systems::selection_transform::{shift_selection_original, bake_base_position};
+use rand::Rng;
+
+/// System to start or stop wave motion on the selection (or all particles when nothing is selected)
+/// Stopping restores each particle's stored base Y; locked particles are left alone
+pub fn handle_wave_toggle(
+    mut commands: Commands,
+    mut wave_state: ResMut<WaveMotionState>,
+    selection_state: Res<ParticleSelectionState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut particle_query: Query<(Entity, &mut Transform, Option<&WaveMotion>), (With<Particle>, Without<Locked>)>,
+) {
+    if !wave_state.toggle_requested {
+        return;
+    }
+    wave_state.toggle_requested = false;
+    
+    let targets: Vec<Entity> = if selection_state.selected_particles.is_empty() {
+        particle_query.iter().map(|(entity, _, _)| entity).collect()
+    } else {
+        selection_state.selected_particles.iter().copied().filter(|entity| particle_query.contains(*entity)).collect()
+    };
+    
+    // Stop when every target is already waving, otherwise start the ones that are not
+    let all_waving = targets.iter().all(|entity| {
+        particle_query.get(*entity).is_ok_and(|(_, _, wave)| wave.is_some())
+    });
+    
+    for entity in targets {
+        let Ok((_, mut transform, wave)) = particle_query.get_mut(entity) else { continue };
+        
+        if all_waving {
+            if let Some(wave) = wave {
+                transform.translation.y = wave.base_y;
+                particle_positions.current_positions.insert(entity, transform.translation);
+            }
+            commands.entity(entity).remove::<WaveMotion>();
+        } else if wave.is_none() {
+            commands.entity(entity).insert(WaveMotion { base_y: transform.translation.y });
+        }
+    }
+}
+
+/// System to ripple particle heights outward from the wave center
+/// y = base_y + amplitude * sin(k * distance - speed * t), with k = 2π / wavelength
+/// Locked particles keep their height while locked
+pub fn animate_wave_particles(
+    time: Res<Time>,
+    mut wave_state: ResMut<WaveMotionState>,
+    mut particle_query: Query<(Entity, &mut Transform, &WaveMotion), (With<Particle>, Without<Locked>)>,
+    mut particle_positions: ResMut<ParticlePositions>,
+) {
+    // Count shown next to the wave button
+    let active_count = particle_query.iter().count();
+    if wave_state.active_count != active_count {
+        wave_state.active_count = active_count;
+    }
+    
+    if active_count == 0 {
+        return;
+    }
+    
+    let k = std::f32::consts::TAU / wave_state.wavelength.max(0.01);
+    let phase = wave_state.speed * time.elapsed_secs();
+    
+    for (entity, mut transform, wave) in particle_query.iter_mut() {
+        let xz = Vec2::new(transform.translation.x, transform.translation.z);
+        let distance = xz.distance(wave_state.center);
+        transform.translation.y = wave.base_y + wave_state.amplitude * (k * distance - phase).sin();
+        particle_positions.current_positions.insert(entity, transform.translation);
+    }
+}
+
+/// System to move selected particles by a random step each frame, staying within the particle bounds
+pub fn animate_jitter_particles(
+    jitter_state: Res<JitterMotionState>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut particle_query: Query<&mut Transform, (With<Particle>, Without<Locked>)>,
+) {
+    if !jitter_state.is_active || selection_state.selected_particles.is_empty() {
+        return;
+    }
+    
+    let selected = selection_state.sorted();
+    
+    let step_size = jitter_state.step_size;
+    for entity in selected {
+        let Ok(mut transform) = particle_query.get_mut(entity) else { continue };
+        
+        let step = Vec3::new(
+            particle_rng.rng.gen_range(-step_size..=step_size),
+            particle_rng.rng.gen_range(-step_size..=step_size),
+            particle_rng.rng.gen_range(-step_size..=step_size),
+        );
+        let new_position = bounds_state.clamp_world(transform.translation + step);
+        let delta = new_position - transform.translation;
+        
+        transform.translation = new_position;
+        bake_base_position(entity, new_position, &mut particle_positions, &bounds_state, &group_state);
+        shift_selection_original(&mut transform_state, entity, delta);
+    }
+}
@@ -6,6 +6,7 @@ use crate::components::MouseButtonState;
 
 pub fn track_mouse_button_state(
     mouse_button_input: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window>,
     mut button_state: ResMut<MouseButtonState>,
 ) {
     // Update previous state BEFORE reading current state
@@ -16,6 +17,11 @@ pub fn track_mouse_button_state(
     // This ensures state matches actual button state, even if events are missed
     button_state.left_pressed = mouse_button_input.pressed(MouseButton::Left);
     button_state.right_pressed = mouse_button_input.pressed(MouseButton::Right);
+    
+    // Remember where the left button went down so a release can be told apart from a camera drag
+    if button_state.left_pressed && !button_state.left_was_pressed {
+        button_state.left_press_position = windows.single().ok().and_then(|window| window.cursor_position());
+    }
 }
 
 pub fn cleanup_mouse_button_state(
@@ -0,0 +1,60 @@
+// systems/notifications.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Notification, Notifications, MaxParticles};
+use crate::constants::{NOTIFICATION_SECONDS, NOTIFICATION_MAX_VISIBLE};
+
+/// Queue a short user-facing message (shown in the viewport corner, dismissed after a few seconds)
+/// A repeat of the newest message restarts its timer and bumps its count instead of stacking,
+/// and the oldest messages are dropped once more than NOTIFICATION_MAX_VISIBLE are queued
+pub fn notify(notifications: &mut Notifications, message: impl Into<String>) {
+    let message = message.into();
+    if let Some(newest) = notifications.messages.back_mut()
+        && newest.message == message
+    {
+        newest.count += 1;
+        newest.remaining = NOTIFICATION_SECONDS;
+        return;
+    }
+    
+    notifications.messages.push_back(Notification {
+        message,
+        count: 1,
+        remaining: NOTIFICATION_SECONDS,
+    });
+    while notifications.messages.len() > NOTIFICATION_MAX_VISIBLE {
+        notifications.messages.pop_front();
+    }
+}
+
+/// System to count down the queued notifications and drop expired ones
+pub fn expire_notifications(
+    time: Res<Time>,
+    mut notifications: ResMut<Notifications>,
+) {
+    if notifications.messages.is_empty() {
+        return;
+    }
+    let delta = time.delta_secs();
+    for notification in notifications.messages.iter_mut() {
+        notification.remaining -= delta;
+    }
+    notifications.messages.retain(|notification| notification.remaining > 0.0);
+}
+
+/// System to notify once when the particle cap starts limiting spawns
+/// Spawners set the clamped flag on every attempt, only the transition is reported
+pub fn notify_particle_cap(
+    max_particles: Res<MaxParticles>,
+    mut notifications: ResMut<Notifications>,
+    mut was_clamped: Local<bool>,
+) {
+    if !max_particles.is_changed() {
+        return;
+    }
+    if max_particles.clamped && !*was_clamped {
+        notify(&mut notifications, format!("Reached particle cap ({})", max_particles.limit));
+    }
+    *was_clamped = max_particles.clamped;
+}
@@ -2,91 +2,85 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{Particle, ParticlePositions, ParticleCreationState, ParticlePlacementMode, ParticleSelectionState, ParticleBoundsState};
-use crate::constants::{PARTICLE_RADIUS, COLOR_WHITE, PARTICLE_GRID_BOUNDS};
+use bevy::ecs::system::SystemParam;
+use crate::components::{Particle, Selected, ParticleLayer, ParticleBaseColor, ParticlePositions, ParticleCreationState, ParticlePlacementMode, ParticleSelectionState, ParticleBoundsState, ParticleGroupState, ParticleRadius, SelectionTransformState, LayerState, MaxParticles, ParticleRng, PendingSpawns, SelectionChanged, PlaceAtCursorState, MouseButtonState, ParticleShape, ParticleShapeState, JustCreated, Locked, Lifetime, StartupParticleCount};
+use crate::constants::{COLOR_WHITE, PLACEMENT_PREVIEW_COLOR, MIN_DRAG_DISTANCE, SPRAY_JITTER, JUST_CREATED_PULSE_HZ, JUST_CREATED_EMISSIVE, LIFETIME_FADE_SECONDS};
+use crate::systems::selection::cursor_plane_point;
+use crate::plugins::viewport_constrained_camera::CameraViewportCursorState;
+use bevy_egui::EguiContexts;
 use rand::Rng;
 
+/// Settings new particles are spawned with: the shape meshes, the radius, and the bounds and
+/// group transform their base positions are stored against
+/// Grouped to keep the spawn helpers and the systems calling them within the argument limits
+#[derive(SystemParam)]
+pub struct SpawnSettings<'w> {
+    pub shape_state: ResMut<'w, ParticleShapeState>,
+    pub particle_radius: Res<'w, ParticleRadius>,
+    pub bounds_state: Res<'w, ParticleBoundsState>,
+    pub group_state: Res<'w, ParticleGroupState>,
+}
+
 /// Spawn a single particle at a specific position
 pub fn spawn_single_particle(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    _materials: &mut ResMut<Assets<StandardMaterial>>,
     particle_positions: &mut ParticlePositions,
     position: Vec3,
+    shape: ParticleShape,
+    mesh: &Handle<Mesh>,
     white_material: &Handle<StandardMaterial>,
+    spawn_settings: &SpawnSettings,
 ) -> Entity {
+    // Mesh uses the base radius; the runtime radius is applied through the transform scale
+    // The spawn material is remembered as the base color restored on deselect
     let entity = commands.spawn((
-        Mesh3d(meshes.add(Sphere::new(PARTICLE_RADIUS))),
+        Mesh3d(mesh.clone()),
         MeshMaterial3d(white_material.clone()),
-        Transform::from_translation(position),
+        Transform::from_translation(position).with_scale(spawn_settings.particle_radius.mesh_scale()),
         Particle,
+        shape,
+        ParticleBaseColor(white_material.clone()),
+        JustCreated::default(),
     )).id();
     
-    // Store normalized base position (for resizing) and current world position
-    // For manually created particles, we'll use the current position as base
-    // This might need adjustment if bounds change, but for now we'll store it as-is
-    let normalized_x = 0.5; // Default normalized position
-    let normalized_y = 0.5;
-    let normalized_z = 0.5;
-    let base_position = Vec3::new(normalized_x, normalized_y, normalized_z);
-    particle_positions.base_positions.insert(entity, base_position);
-    particle_positions.current_positions.insert(entity, position);
+    // Store the spawn position normalized against the current bounds (and group transform)
+    // plus the current world position, so bounds resizing moves the particle proportionally
+    crate::systems::selection_transform::bake_base_position(entity, position, particle_positions, &spawn_settings.bounds_state, &spawn_settings.group_state);
     
     entity
 }
 
-/// Spawn particles randomly within bounds (original behavior)
-pub fn spawn_particles_random(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    particle_positions: &mut ParticlePositions,
+/// Generate random positions within bounds (original behavior)
+pub fn random_positions(
+    rng: &mut impl Rng,
     bounds_state: Option<&ParticleBoundsState>,
     count: usize,
-) {
-    let white_material = materials.add(COLOR_WHITE);
-    
+) -> Vec<Vec3> {
     // Get bounds from resource or use defaults
-    let bounds_x = bounds_state.map(|bs| bs.bounds_x).unwrap_or(PARTICLE_GRID_BOUNDS);
-    let bounds_z = bounds_state.map(|bs| bs.bounds_z).unwrap_or(PARTICLE_GRID_BOUNDS);
-    let bounds_y_height = bounds_state.map(|bs| bs.bounds_y_height).unwrap_or(1.0);
-    let bounds_y_min = 1.0; // Always starts at 1.0
+    let default_bounds = ParticleBoundsState::default();
+    let bounds_state = bounds_state.unwrap_or(&default_bounds);
     
-    let mut rng = rand::thread_rng();
-    
-    for _ in 0..count {
+    (0..count).map(|_| {
         // Generate random normalized positions (0-1 range)
         let normalized_x = rng.gen_range(0.0..=1.0);
         let normalized_z = rng.gen_range(0.0..=1.0);
         let normalized_y = rng.gen_range(0.0..=1.0);
         
         // Convert normalized to world coordinates using current bounds
-        let x = (normalized_x - 0.5) * bounds_x;
-        let z = (normalized_z - 0.5) * bounds_z;
-        let y = bounds_y_min + normalized_y * bounds_y_height;
-        
-        let position = Vec3::new(x, y, z);
-        spawn_single_particle(commands, meshes, materials, particle_positions, position, &white_material);
-    }
+        bounds_state.normalized_to_world(Vec3::new(normalized_x, normalized_y, normalized_z))
+    }).collect()
 }
 
-/// Spawn particles randomly inside a sphere
-pub fn spawn_particles_in_ball(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    particle_positions: &mut ParticlePositions,
+/// Generate random positions inside a sphere
+pub fn ball_positions(
+    rng: &mut impl Rng,
     center: Vec3,
     radius: f32,
     y_min: f32,
     count: usize,
-) {
-    let white_material = materials.add(COLOR_WHITE);
-    let mut rng = rand::thread_rng();
-    
-    for _ in 0..count {
+) -> Vec<Vec3> {
+    (0..count).map(|_| {
         // Generate random point inside sphere using rejection sampling
-        let mut position;
         loop {
             // Generate point in cube [-radius, radius]^3
             let x = rng.gen_range(-radius..=radius);
@@ -96,34 +90,26 @@ pub fn spawn_particles_in_ball(
             // Check if point is inside sphere
             let distance_from_center = (x * x + y * y + z * z).sqrt();
             if distance_from_center <= radius {
-                position = center + Vec3::new(x, y, z);
+                let mut position = center + Vec3::new(x, y, z);
                 // Ensure Y is at least y_min
                 position.y = position.y.max(y_min);
-                break;
+                break position;
             }
         }
-        
-        spawn_single_particle(commands, meshes, materials, particle_positions, position, &white_material);
-    }
+    }).collect()
 }
 
-/// Spawn particles randomly inside a cube (axis-aligned box)
-pub fn spawn_particles_in_cube(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    particle_positions: &mut ParticlePositions,
+/// Generate random positions inside a cube (axis-aligned box)
+pub fn cube_positions(
+    rng: &mut impl Rng,
     center: Vec3,
     size: Vec3,
     y_min: f32,
     count: usize,
-) {
-    let white_material = materials.add(COLOR_WHITE);
-    let mut rng = rand::thread_rng();
-    
+) -> Vec<Vec3> {
     let half_size = size * 0.5;
     
-    for _ in 0..count {
+    (0..count).map(|_| {
         // Generate random point inside cube
         let x = rng.gen_range(-half_size.x..=half_size.x);
         let y = rng.gen_range(-half_size.y..=half_size.y);
@@ -132,8 +118,24 @@ pub fn spawn_particles_in_cube(
         let mut position = center + Vec3::new(x, y, z);
         // Ensure Y is at least y_min
         position.y = position.y.max(y_min);
-        
-        spawn_single_particle(commands, meshes, materials, particle_positions, position, &white_material);
+        position
+    }).collect()
+}
+
+/// Spawn particles at the given positions, sharing one white material
+pub fn spawn_particles_at(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    particle_positions: &mut ParticlePositions,
+    positions: impl IntoIterator<Item = Vec3>,
+    spawn_settings: &mut SpawnSettings,
+) {
+    let white_material = materials.add(COLOR_WHITE);
+    let shape = spawn_settings.shape_state.shape;
+    let mesh = spawn_settings.shape_state.mesh(shape, meshes);
+    for position in positions {
+        spawn_single_particle(commands, particle_positions, position, shape, &mesh, &white_material, spawn_settings);
     }
 }
 
@@ -144,48 +146,423 @@ pub fn handle_particle_creation(
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut particle_positions: ResMut<ParticlePositions>,
     mut creation_state: ResMut<ParticleCreationState>,
-    bounds_state: Option<Res<ParticleBoundsState>>,
+    mut max_particles: ResMut<MaxParticles>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut pending_spawns: ResMut<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
 ) {
     if creation_state.create_requested {
         creation_state.create_requested = false;
         
-        match creation_state.placement_mode {
-            ParticlePlacementMode::Random => {
-                spawn_particles_random(
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                    &mut particle_positions,
-                    bounds_state.as_deref(),
-                    creation_state.batch_count,
-                );
-            }
-            ParticlePlacementMode::Ball => {
-                spawn_particles_in_ball(
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                    &mut particle_positions,
-                    creation_state.ball_center,
-                    creation_state.ball_radius,
-                    creation_state.y_min,
-                    creation_state.batch_count,
-                );
+        // Only spawn up to the remaining budget under the particle cap
+        let total = particle_positions.particle_count() + pending_spawns.positions.len();
+        let count = creation_state.batch_count.min(max_particles.remaining(total));
+        max_particles.clamped = count < creation_state.batch_count;
+        if max_particles.clamped {
+            warn!(
+                "Particle cap of {} reached: creating {} of {} requested particles",
+                max_particles.limit, count, creation_state.batch_count
+            );
+        }
+        if count == 0 {
+            return;
+        }
+        
+        // Positions are generated up front, so streaming yields the same set as spawning at once
+        let rng = &mut particle_rng.rng;
+        let positions = match creation_state.placement_mode {
+            ParticlePlacementMode::Random => random_positions(rng, Some(&spawn_settings.bounds_state), count),
+            ParticlePlacementMode::Ball => ball_positions(
+                rng,
+                creation_state.ball_center,
+                creation_state.ball_radius,
+                creation_state.y_min,
+                count,
+            ),
+            ParticlePlacementMode::Cube => cube_positions(
+                rng,
+                creation_state.cube_center,
+                creation_state.cube_size,
+                creation_state.y_min,
+                count,
+            ),
+        };
+        
+        if pending_spawns.streaming {
+            pending_spawns.positions.extend(positions);
+        } else {
+            spawn_particles_at(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                &mut particle_positions,
+                positions,
+                &mut spawn_settings,
+            );
+        }
+    }
+}
+
+/// System to draw a wireframe preview of the Ball/Cube spawn volume (nothing in Random mode)
+pub fn draw_placement_preview(
+    mut gizmos: Gizmos,
+    creation_state: Res<ParticleCreationState>,
+) {
+    match creation_state.placement_mode {
+        ParticlePlacementMode::Random => {}
+        ParticlePlacementMode::Ball => {
+            gizmos.sphere(
+                Isometry3d::from_translation(creation_state.ball_center),
+                creation_state.ball_radius,
+                PLACEMENT_PREVIEW_COLOR,
+            );
+        }
+        ParticlePlacementMode::Cube => {
+            gizmos.cube(
+                Transform::from_translation(creation_state.cube_center).with_scale(creation_state.cube_size),
+                PLACEMENT_PREVIEW_COLOR,
+            );
+        }
+    }
+}
+
+/// Position the place-at-cursor tool would spawn at, raised to the creation y_min
+fn place_at_cursor_position(
+    window: &Window,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    place_state: &PlaceAtCursorState,
+    creation_state: &ParticleCreationState,
+) -> Option<Vec3> {
+    let mut position = cursor_plane_point(window, camera_query, place_state.plane_height)?;
+    position.y = position.y.max(creation_state.y_min);
+    Some(position)
+}
+
+/// System to spawn a single particle at the cursor on click while the place-at-cursor tool is active
+pub fn handle_place_at_cursor(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut max_particles: ResMut<MaxParticles>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    place_state: Res<PlaceAtCursorState>,
+    creation_state: Res<ParticleCreationState>,
+    button_state: Res<MouseButtonState>,
+    pending_spawns: Res<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
+    mut white_material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    // Only on release (transition from pressed to not pressed); spraying places its own particles
+    if !place_state.is_active || place_state.spray || !button_state.left_was_pressed || button_state.left_pressed {
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    
+    // A release after dragging (e.g. rotating the camera) does not place a particle
+    let (Some(cursor_pos), Some(press_pos)) = (window.cursor_position(), button_state.left_press_position) else { return };
+    if (cursor_pos - press_pos).length() >= MIN_DRAG_DISTANCE {
+        return;
+    }
+    
+    let Some(position) = place_at_cursor_position(window, &camera_query, &place_state, &creation_state) else { return };
+    
+    let total = particle_positions.particle_count() + pending_spawns.positions.len();
+    max_particles.clamped = max_particles.remaining(total) == 0;
+    if max_particles.clamped {
+        warn!("Particle cap of {} reached: not placing a particle", max_particles.limit);
+        return;
+    }
+    
+    let material = white_material.get_or_insert_with(|| materials.add(COLOR_WHITE)).clone();
+    let shape = spawn_settings.shape_state.shape;
+    let mesh = spawn_settings.shape_state.mesh(shape, &mut meshes);
+    spawn_single_particle(
+        &mut commands,
+        &mut particle_positions,
+        position,
+        shape,
+        &mesh,
+        &material,
+        &spawn_settings,
+    );
+}
+
+/// System to hand left drags to the spray tool instead of the camera rotation while spraying
+pub fn block_camera_rotation_while_spraying(
+    place_state: Res<PlaceAtCursorState>,
+    mut cursor_state: ResMut<CameraViewportCursorState>,
+) {
+    let spraying = place_state.is_active && place_state.spray;
+    if cursor_state.rotation_blocked != spraying {
+        cursor_state.rotation_blocked = spraying;
+    }
+}
+
+/// System to spray particles along the cursor path while the left button is held
+/// Spawns accumulate over time so the rate does not depend on the frame rate
+pub fn handle_spray_placement(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut max_particles: ResMut<MaxParticles>,
+    mut particle_rng: ResMut<ParticleRng>,
+    time: Res<Time>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    place_state: Res<PlaceAtCursorState>,
+    creation_state: Res<ParticleCreationState>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    pending_spawns: Res<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
+    // Spawn budget carried between frames and the cached spawn material
+    mut spray: Local<(f32, Option<Handle<StandardMaterial>>)>,
+) {
+    let (spray_budget, white_material) = &mut *spray;
+    if !place_state.is_active || !place_state.spray || !mouse_button_input.pressed(MouseButton::Left) {
+        // The next press places its first particle immediately
+        *spray_budget = 1.0;
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    let Some(center) = place_at_cursor_position(window, &camera_query, &place_state, &creation_state) else { return };
+    
+    *spray_budget += place_state.spray_rate.max(0.0) * time.delta_secs();
+    let count = spray_budget.floor() as usize;
+    if count == 0 {
+        return;
+    }
+    *spray_budget -= count as f32;
+    
+    // Stop at the cap
+    let total = particle_positions.particle_count() + pending_spawns.positions.len();
+    let remaining = max_particles.remaining(total);
+    max_particles.clamped = remaining < count;
+    let count = count.min(remaining);
+    
+    let material = white_material.get_or_insert_with(|| materials.add(COLOR_WHITE)).clone();
+    let shape = spawn_settings.shape_state.shape;
+    let mesh = spawn_settings.shape_state.mesh(shape, &mut meshes);
+    for _ in 0..count {
+        let jitter = Vec3::new(
+            particle_rng.rng.gen_range(-SPRAY_JITTER..=SPRAY_JITTER),
+            0.0,
+            particle_rng.rng.gen_range(-SPRAY_JITTER..=SPRAY_JITTER),
+        );
+        spawn_single_particle(
+            &mut commands,
+            &mut particle_positions,
+            center + jitter,
+            shape,
+            &mesh,
+            &material,
+            &spawn_settings,
+        );
+    }
+}
+
+/// System to draw a marker where the place-at-cursor tool would spawn a particle
+pub fn draw_place_at_cursor_preview(
+    mut gizmos: Gizmos,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    place_state: Res<PlaceAtCursorState>,
+    creation_state: Res<ParticleCreationState>,
+    particle_radius: Res<ParticleRadius>,
+) {
+    if !place_state.is_active {
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    let Some(position) = place_at_cursor_position(window, &camera_query, &place_state, &creation_state) else { return };
+    
+    gizmos.sphere(Isometry3d::from_translation(position), particle_radius.0 * 2.0, PLACEMENT_PREVIEW_COLOR);
+}
+
+/// System to spawn queued particles a chunk per frame
+pub fn process_pending_spawns(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut pending_spawns: ResMut<PendingSpawns>,
+    mut spawn_settings: SpawnSettings,
+) {
+    if pending_spawns.positions.is_empty() {
+        return;
+    }
+    
+    let chunk = pending_spawns.chunk_size.max(1).min(pending_spawns.positions.len());
+    let positions: Vec<Vec3> = pending_spawns.positions.drain(..chunk).collect();
+    spawn_particles_at(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut particle_positions,
+        positions,
+        &mut spawn_settings,
+    );
+}
+
+/// System to copy and paste the selection with Ctrl+C / Ctrl+V
+/// Copying with nothing selected keeps the clipboard; ignored while an egui widget has keyboard focus
+pub fn handle_copy_paste_shortcuts(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut contexts: EguiContexts,
+    mut creation_state: ResMut<ParticleCreationState>,
+    selection_state: Res<ParticleSelectionState>,
+) {
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+    
+    let ctrl = keyboard_input.pressed(KeyCode::ControlLeft) || keyboard_input.pressed(KeyCode::ControlRight);
+    if !ctrl {
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::KeyC) && !selection_state.selected_particles.is_empty() {
+        creation_state.clipboard = selection_state.sorted();
+    }
+    if keyboard_input.just_pressed(KeyCode::KeyV) {
+        creation_state.paste_requested = true;
+    }
+}
+
+/// System to duplicate the selected (or pasted) particles, either at an offset or mirrored
+/// A mirror copy reflects the clones across the plane through the selection centroid normal to the chosen axis
+/// The clones become the new selection, so repeated duplication keeps stacking the offset
+pub fn handle_particle_duplication(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut spawn_settings: SpawnSettings,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut creation_state: ResMut<ParticleCreationState>,
+    mut selection_state: ResMut<ParticleSelectionState>,
+    mut layer_state: ResMut<LayerState>,
+    mut max_particles: ResMut<MaxParticles>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+    pending_spawns: Res<PendingSpawns>,
+    particle_query: Query<(&Transform, &ParticleBaseColor, Option<&ParticleLayer>, Option<&ParticleShape>), With<Particle>>,
+) {
+    let mirror_axis = creation_state.mirror_copy_requested.take();
+    let paste = std::mem::take(&mut creation_state.paste_requested);
+    if !creation_state.duplicate_requested && mirror_axis.is_none() && !paste {
+        return;
+    }
+    creation_state.duplicate_requested = false;
+    
+    // Pasting clones the clipboard (minus particles removed since), the buttons clone the selection
+    let originals: Vec<Entity> = if paste {
+        creation_state.clipboard.iter().copied().filter(|entity| particle_query.contains(*entity)).collect()
+    } else {
+        selection_state.sorted()
+    };
+    
+    // Nothing selected (or copied): nothing to duplicate
+    if originals.is_empty() || mirror_axis.is_some_and(|axis| axis > 2) {
+        return;
+    }
+    
+    // Mirror plane passes through the centroid of the selected particles
+    let centroid = {
+        let positions: Vec<Vec3> = originals.iter()
+            .filter_map(|entity| particle_query.get(*entity).ok())
+            .map(|(transform, ..)| transform.translation)
+            .collect();
+        positions.iter().sum::<Vec3>() / positions.len().max(1) as f32
+    };
+    
+    // Only clone up to the remaining budget under the particle cap
+    let total = particle_positions.particle_count() + pending_spawns.positions.len();
+    let count = originals.len().min(max_particles.remaining(total));
+    max_particles.clamped = count < originals.len();
+    if max_particles.clamped {
+        warn!(
+            "Particle cap of {} reached: duplicating {} of {} selected particles",
+            max_particles.limit, count, originals.len()
+        );
+    }
+    if count == 0 {
+        return;
+    }
+    
+    let mut clones = Vec::with_capacity(count);
+    
+    for entity in originals.iter().take(count) {
+        let Ok((transform, base_color, layer, shape)) = particle_query.get(*entity) else { continue };
+        
+        // Clone keeps the original's base color and shape
+        let position = match mirror_axis {
+            Some(axis) => {
+                let mut mirrored = transform.translation;
+                mirrored[axis] = 2.0 * centroid[axis] - mirrored[axis];
+                mirrored
             }
-            ParticlePlacementMode::Cube => {
-                spawn_particles_in_cube(
-                    &mut commands,
-                    &mut meshes,
-                    &mut materials,
-                    &mut particle_positions,
-                    creation_state.cube_center,
-                    creation_state.cube_size,
-                    creation_state.y_min,
-                    creation_state.batch_count,
-                );
+            None => transform.translation + creation_state.duplicate_offset,
+        };
+        let shape = shape.copied().unwrap_or_default();
+        let mesh = spawn_settings.shape_state.mesh(shape, &mut meshes);
+        let clone = spawn_single_particle(
+            &mut commands,
+            &mut particle_positions,
+            position,
+            shape,
+            &mesh,
+            &base_color.0,
+            &spawn_settings,
+        );
+        
+        // Copy per-particle attributes (layer membership)
+        if let Some(layer) = layer {
+            commands.entity(clone).insert(layer.clone());
+            if let Some(entry) = layer_state.layers.iter_mut().find(|entry| entry.name == layer.0) {
+                entry.members.insert(clone);
             }
         }
+        
+        clones.push(clone);
+    }
+    
+    // Deselect the originals (the whole selection when pasting)
+    let removed = selection_state.sorted();
+    for entity in removed.iter() {
+        commands.entity(*entity).remove::<Selected>();
+        selection_state.selected_particles.remove(entity);
+    }
+    
+    // Select the clones; a next paste clones them again, one more offset along
+    for clone in clones.iter() {
+        commands.entity(*clone).insert(Selected);
+        selection_state.selected_particles.insert(*clone);
+    }
+    if paste {
+        creation_state.clipboard = clones.clone();
     }
+    selection_changed.write(SelectionChanged { added: clones, removed });
+    
+    // Hidden layers also hide their new members
+    layer_state.visibility_changed = true;
+}
+
+/// Despawn a particle and drop it from the position and layer bookkeeping
+/// Shared by every removal path so no resource keeps a dangling entity
+pub fn despawn_particle(
+    commands: &mut Commands,
+    particle_positions: &mut ParticlePositions,
+    layer_state: &mut LayerState,
+    entity: Entity,
+) {
+    commands.entity(entity).despawn();
+    particle_positions.base_positions.remove(&entity);
+    particle_positions.current_positions.remove(&entity);
+    layer_state.remove_particle(entity);
 }
 
 /// System to handle particle removal requests
@@ -194,20 +571,31 @@ pub fn handle_particle_removal(
     mut particle_positions: ResMut<ParticlePositions>,
     mut creation_state: ResMut<ParticleCreationState>,
     mut selection_state: ResMut<ParticleSelectionState>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut layer_state: ResMut<LayerState>,
+    mut pending_spawns: ResMut<PendingSpawns>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
     particle_query: Query<Entity, With<Particle>>,
 ) {
-    if creation_state.remove_all_requested {
+    // A reset removes everything here; handle_particle_reset then respawns
+    if creation_state.remove_all_requested || creation_state.reset_requested {
         creation_state.remove_all_requested = false;
         
+        // Drop particles still queued for streaming
+        pending_spawns.positions.clear();
+        
         // Remove all particles
         for entity in particle_query.iter() {
-            commands.entity(entity).despawn();
-            particle_positions.base_positions.remove(&entity);
-            particle_positions.current_positions.remove(&entity);
+            despawn_particle(&mut commands, &mut particle_positions, &mut layer_state, entity);
         }
         
         // Clear selection after removing all particles
-        selection_state.selected_particles.clear();
+        let removed: Vec<Entity> = selection_state.selected_particles.drain().collect();
+        if !removed.is_empty() {
+            selection_changed.write(SelectionChanged { added: Vec::new(), removed });
+        }
+        // Drop transform originals that refer to despawned particles
+        crate::systems::reset_selection_transform(&mut transform_state);
     } else if creation_state.remove_selected_requested {
         creation_state.remove_selected_requested = false;
         
@@ -219,12 +607,271 @@ pub fn handle_particle_removal(
         
         // Remove selected particles
         for entity in entities_to_remove.iter() {
-            commands.entity(*entity).despawn();
-            particle_positions.base_positions.remove(entity);
-            particle_positions.current_positions.remove(entity);
+            despawn_particle(&mut commands, &mut particle_positions, &mut layer_state, *entity);
         }
         
         // Clear selection after removal
-        selection_state.selected_particles.clear();
+        let removed: Vec<Entity> = selection_state.selected_particles.drain().collect();
+        if !removed.is_empty() {
+            selection_changed.write(SelectionChanged { added: Vec::new(), removed });
+        }
+        // Drop transform originals that refer to despawned particles
+        crate::systems::reset_selection_transform(&mut transform_state);
+    }
+}
+
+/// System to respawn the startup distribution after Reset Particles removed every particle
+/// The RNG is reseeded, so a reset reproduces the startup positions for the current bounds
+pub fn handle_particle_reset(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut creation_state: ResMut<ParticleCreationState>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut max_particles: ResMut<MaxParticles>,
+    startup_count: Res<StartupParticleCount>,
+    mut spawn_settings: SpawnSettings,
+) {
+    if !creation_state.reset_requested {
+        return;
+    }
+    creation_state.reset_requested = false;
+    
+    // Copied particles were despawned along with the rest
+    creation_state.clipboard.clear();
+    // Every particle was removed, so the whole cap is free; a cap lowered since startup cuts the count
+    let count = startup_count.0.min(max_particles.limit);
+    max_particles.clamped = count < startup_count.0;
+    *particle_rng = ParticleRng::default();
+    crate::setup::spawn_initial_particles(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut particle_positions,
+        &mut particle_rng,
+        count,
+        &mut spawn_settings,
+    );
+}
+
+/// System to pulse the emissive of newly created particles, then restore their base material
+/// Pulsing uses a copy of each base material (shared by all new particles with that base),
+/// so the base material itself is never modified
+pub fn pulse_new_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_query: Query<(Entity, &mut JustCreated, &mut MeshMaterial3d<StandardMaterial>, &ParticleBaseColor, Has<Locked>)>,
+    mut pulse_materials: Local<std::collections::HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>>,
+) {
+    if particle_query.is_empty() {
+        // Dropping the handles frees the pulse copies
+        pulse_materials.clear();
+        return;
+    }
+    
+    let phase = time.elapsed_secs() * JUST_CREATED_PULSE_HZ * std::f32::consts::TAU;
+    let intensity = phase.sin() * 0.5 + 0.5;
+    for handle in pulse_materials.values() {
+        if let Some(material) = materials.get_mut(handle) {
+            material.emissive = JUST_CREATED_EMISSIVE * intensity;
+        }
+    }
+    
+    for (entity, mut just_created, mut material, base_color, is_locked) in particle_query.iter_mut() {
+        just_created.timer.tick(time.delta());
+        
+        // Locked particles show their tint instead (it owns the material while locked)
+        if just_created.timer.is_finished() || is_locked {
+            if !is_locked {
+                material.0 = base_color.0.clone();
+            }
+            commands.entity(entity).remove::<JustCreated>();
+            continue;
+        }
+        
+        let base_id = base_color.0.id();
+        let pulse_handle = match pulse_materials.get(&base_id) {
+            Some(handle) => handle.clone(),
+            None => {
+                let mut pulse_material = materials.get(&base_color.0).cloned().unwrap_or_default();
+                pulse_material.emissive = JUST_CREATED_EMISSIVE * intensity;
+                let handle = materials.add(pulse_material);
+                pulse_materials.insert(base_id, handle.clone());
+                handle
+            }
+        };
+        if material.0 != pulse_handle {
+            material.0 = pulse_handle;
+        }
+    }
+}
+
+/// System to give newly spawned particles a lifetime when that option is enabled
+pub fn assign_particle_lifetime(
+    mut commands: Commands,
+    creation_state: Res<ParticleCreationState>,
+    added_query: Query<Entity, Added<Particle>>,
+) {
+    if !creation_state.lifetime_enabled {
+        return;
+    }
+    for entity in added_query.iter() {
+        commands.entity(entity).insert(Lifetime { remaining: creation_state.lifetime_seconds });
+    }
+}
+
+/// System to count down particle lifetimes, fade expiring particles and despawn expired ones
+/// Fading uses a per-particle copy of the base material, dropped again when the particle is removed
+pub fn update_particle_lifetimes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut layer_state: ResMut<LayerState>,
+    mut selection_state: ResMut<ParticleSelectionState>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+    mut particle_query: Query<(Entity, &mut Lifetime, &mut MeshMaterial3d<StandardMaterial>, &ParticleBaseColor, Has<Locked>, Has<JustCreated>)>,
+    mut fade_materials: Local<std::collections::HashMap<Entity, Handle<StandardMaterial>>>,
+) {
+    let delta = time.delta_secs();
+    let mut removed = Vec::new();
+    
+    for (entity, mut lifetime, mut material, base_color, is_locked, is_pulsing) in particle_query.iter_mut() {
+        lifetime.remaining -= delta;
+        
+        if lifetime.remaining <= 0.0 {
+            despawn_particle(&mut commands, &mut particle_positions, &mut layer_state, entity);
+            fade_materials.remove(&entity);
+            if selection_state.selected_particles.remove(&entity) {
+                transform_state.original_selection_positions.remove(&entity);
+                removed.push(entity);
+            }
+            continue;
+        }
+        
+        // Locked tint and the creation pulse own the material while they are shown
+        if lifetime.remaining > LIFETIME_FADE_SECONDS || is_locked || is_pulsing {
+            continue;
+        }
+        
+        let fade_handle = fade_materials.entry(entity).or_insert_with(|| {
+            let mut fade_material = materials.get(&base_color.0).cloned().unwrap_or_default();
+            fade_material.alpha_mode = AlphaMode::Blend;
+            materials.add(fade_material)
+        }).clone();
+        if let Some(fade_material) = materials.get_mut(&fade_handle) {
+            let alpha = (lifetime.remaining / LIFETIME_FADE_SECONDS).clamp(0.0, 1.0);
+            fade_material.base_color.set_alpha(alpha);
+        }
+        if material.0 != fade_handle {
+            material.0 = fade_handle;
+        }
+    }
+    
+    // Particles removed by other paths no longer need their fade copy
+    fade_materials.retain(|entity, _| particle_query.contains(*entity));
+    
+    if !removed.is_empty() {
+        selection_changed.write(SelectionChanged { added: Vec::new(), removed });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_harness::*;
+    
+    #[test]
+    fn removing_a_transformed_selection_clears_the_transform_state() {
+        let mut app = test_app();
+        app.add_systems(Update, (
+            crate::systems::update_selection_original_positions,
+            crate::systems::update_selection_transform,
+            handle_particle_removal,
+        ).chain());
+        let particles = spawn_particles(&mut app, &[Vec3::new(-1.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.5, 2.0)]);
+        set_selection(&mut app, &particles[..2]);
+        app.update();
+        {
+            let mut transform_state = app.world_mut().resource_mut::<SelectionTransformState>();
+            transform_state.position_offset = Vec3::new(1.0, 0.0, 0.0);
+            transform_state.scale = Vec3::splat(2.0);
+        }
+        run_updates(&mut app, 2);
+        
+        app.world_mut().resource_mut::<ParticleCreationState>().remove_selected_requested = true;
+        app.update();
+        
+        let transform_state = app.world().resource::<SelectionTransformState>();
+        assert!(transform_state.original_selection_positions.is_empty());
+        assert_eq!(transform_state.position_offset, Vec3::ZERO);
+        assert_eq!(transform_state.scale, Vec3::ONE);
+        assert!(app.world().resource::<ParticleSelectionState>().selected_particles.is_empty());
+        
+        let particle_positions = app.world().resource::<ParticlePositions>();
+        for entity in particles[..2].iter() {
+            assert!(app.world().get_entity(*entity).is_err());
+            assert!(!particle_positions.current_positions.contains_key(entity));
+            assert!(!particle_positions.base_positions.contains_key(entity));
+        }
+        assert_near(position(&app, particles[2]), Vec3::new(0.0, 1.5, 2.0));
+    }
+    
+    #[test]
+    fn random_positions_fallback_spreads_like_the_bounds_state() {
+        let spread = |positions: &[Vec3]| positions.iter().fold(
+            (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+            |(min, max), position| (min.min(position.xz()), max.max(position.xz())),
+        );
+        let bounds_state = ParticleBoundsState::default();
+        let fallback = random_positions(&mut ParticleRng::default().rng, None, 500);
+        let bounded = random_positions(&mut ParticleRng::default().rng, Some(&bounds_state), 500);
+        
+        let (fallback_min, fallback_max) = spread(&fallback);
+        let (bounded_min, bounded_max) = spread(&bounded);
+        assert!(fallback_min.abs_diff_eq(bounded_min, 1e-5) && fallback_max.abs_diff_eq(bounded_max, 1e-5));
+        
+        // Both fill the bounds region (not half or twice of it)
+        assert!(fallback_min.cmpge(bounds_state.min.xz()).all() && fallback_max.cmple(bounds_state.max.xz()).all());
+        let size = bounds_state.max.xz() - bounds_state.min.xz();
+        assert!((fallback_max - fallback_min).cmpgt(size * 0.9).all());
+    }
+    
+    #[test]
+    fn created_particles_move_proportionally_when_the_bounds_change() {
+        let mut app = test_app();
+        app.add_systems(Update, crate::systems::update_particle_bounds);
+        
+        // Default bounds span -5..5 in X/Z and 1..2 in Y
+        let particles = spawn_particles(&mut app, &[Vec3::new(2.5, 1.25, -1.25)]);
+        {
+            let mut bounds_state = app.world_mut().resource_mut::<ParticleBoundsState>();
+            bounds_state.min = Vec3::new(-10.0, 1.0, -10.0);
+            bounds_state.max = Vec3::new(10.0, 3.0, 10.0);
+        }
+        app.update();
+        
+        // Same normalized position (0.75, 0.25, 0.375) in the new bounds, not the center
+        assert_near(position(&app, particles[0]), Vec3::new(5.0, 1.5, -2.5));
+    }
+    
+    #[test]
+    fn reset_respects_a_lowered_particle_cap() {
+        let mut app = test_app();
+        app.add_systems(Update, (handle_particle_removal, handle_particle_reset).chain());
+        app.world_mut().resource_mut::<StartupParticleCount>().0 = 50;
+        app.world_mut().resource_mut::<MaxParticles>().limit = 20;
+        spawn_particles(&mut app, &[Vec3::new(0.0, 1.0, 0.0)]);
+        
+        app.world_mut().resource_mut::<ParticleCreationState>().reset_requested = true;
+        app.update();
+        
+        let max_particles = app.world().resource::<MaxParticles>();
+        assert_eq!(max_particles.limit, 20);
+        assert!(max_particles.clamped);
+        assert_eq!(app.world().resource::<ParticlePositions>().particle_count(), 20);
     }
 }
@@ -2,19 +2,27 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{Particle, Selected, ParticleSelectionState, MouseButtonState, ParticleBoundsState, ParticleGroupState};
-use crate::constants::{PARTICLE_RADIUS, COLOR_WHITE, COLOR_GREEN};
+use crate::components::{Particle, Selected, ParticleSelectionState, MouseButtonState, ParticleBoundsState, ParticleGroupState, ParticleRadius, Locked, InMotion, SoloMotion, Motion1State, ParticleBaseColor, SelectionChanged, ParticleShape, GridState};
+use crate::constants::{COLOR_LOCKED, MOTION_TINT_EMISSIVE, MIN_DRAG_DISTANCE, BOUNDS_FIT_MARGIN};
 
 pub fn handle_particle_selection(
     windows: Query<&Window>,
     camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    mut particle_query: Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, Without<Selected>)>,
-    mut selected_query: Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, With<Selected>)>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    particle_query: Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, Without<Selected>)>,
+    selected_query: Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, With<Selected>)>,
     mut commands: Commands,
     mut selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
     button_state: Res<MouseButtonState>,
+    particle_radius: Res<ParticleRadius>,
+    brush_state: Res<crate::components::BrushSelectState>,
+    place_state: Res<crate::components::PlaceAtCursorState>,
 ) {
+    // Clicks belong to the brush or placement tool while one is active
+    if brush_state.is_active || place_state.is_active {
+        return;
+    }
+    
     // Use tracked state to detect release (transition from pressed to not pressed)
     // This ensures we always detect button release even if just_released() event was missed
     if button_state.left_was_pressed && !button_state.left_pressed {
@@ -25,8 +33,16 @@ pub fn handle_particle_selection(
     
     let Ok(window) = windows.single() else { return };
     
+    // Read the cursor once and use it for both viewport detection and ray construction
+    let Some(cursor_pos) = window.cursor_position() else { return };
+    
+    // A release after dragging (e.g. rotating the camera) is not a selection click
+    let Some(press_pos) = button_state.left_press_position else { return };
+    if (cursor_pos - press_pos).length() >= MIN_DRAG_DISTANCE {
+        return;
+    }
+    
     // Find camera whose viewport contains the cursor
-    let cursor_pos = window.cursor_position().unwrap_or_default();
     let cursor_physical = cursor_pos * window.scale_factor() as f32;
     
     let mut selected_camera = None;
@@ -44,40 +60,43 @@ pub fn handle_particle_selection(
     
     let Some((camera, camera_transform)) = selected_camera else { return };
     
-    // Get mouse position relative to viewport
-    let Some(cursor_pos) = window.cursor_position() else { return };
-    
     // Convert screen position to world ray - viewport_to_world handles viewport offset automatically
     let Ok(ray) = camera.viewport_to_world(camera_transform, cursor_pos) else { return };
     
     // Find closest particle hit by ray
-    let closest_hit = find_closest_particle_hit(ray, &particle_query, &selected_query);
+    let closest_hit = find_closest_particle_hit(ray, &particle_query, &selected_query, particle_radius.0);
     
     // Toggle selection of closest hit particle
     if let Some(entity) = closest_hit {
         toggle_particle_selection(
             entity,
-            &mut particle_query,
-            &mut selected_query,
-            &mut materials,
+            &particle_query,
+            &selected_query,
             &mut commands,
             &mut selection_state,
+            &mut selection_changed,
         );
     }
 }
 
 fn find_closest_particle_hit(
     ray: impl std::borrow::Borrow<bevy::math::Ray3d>,
-    particle_query: &Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, Without<Selected>)>,
-    selected_query: &Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, With<Selected>)>,
+    particle_query: &Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, Without<Selected>)>,
+    selected_query: &Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, With<Selected>)>,
+    particle_radius: f32,
 ) -> Option<Entity> {
     let ray = ray.borrow();
     let mut closest_hit: Option<(Entity, f32)> = None;
     let ray_dir = *ray.direction;
     
-    // Check unselected particles
-    for (entity, transform, _) in particle_query.iter() {
-        if let Some(distance) = raycast_particle(ray, transform, ray_dir) {
+    // Check unselected particles (hidden particles cannot be picked)
+    for (entity, transform, visibility, shape) in particle_query.iter() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        // Hit-test against the shape's bounding sphere
+        let hit_radius = particle_radius * shape.copied().unwrap_or_default().bounding_radius_factor();
+        if let Some(distance) = raycast_particle(ray, transform, ray_dir, hit_radius) {
             if let Some((_, closest_dist)) = closest_hit {
                 if distance < closest_dist {
                     closest_hit = Some((entity, distance));
@@ -88,9 +107,13 @@ fn find_closest_particle_hit(
         }
     }
     
-    // Check selected particles
-    for (entity, transform, _) in selected_query.iter() {
-        if let Some(distance) = raycast_particle(ray, transform, ray_dir) {
+    // Check selected particles (hidden particles cannot be picked)
+    for (entity, transform, visibility, shape) in selected_query.iter() {
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        let hit_radius = particle_radius * shape.copied().unwrap_or_default().bounding_radius_factor();
+        if let Some(distance) = raycast_particle(ray, transform, ray_dir, hit_radius) {
             if let Some((_, closest_dist)) = closest_hit {
                 if distance < closest_dist {
                     closest_hit = Some((entity, distance));
@@ -104,10 +127,64 @@ fn find_closest_particle_hit(
     closest_hit.map(|(entity, _)| entity)
 }
 
+/// System to orbit only the particle under the cursor while solo-on-hover is enabled
+/// The hovered particle gets a transient InMotion (tagged SoloMotion) that is removed when the
+/// cursor leaves it; particles already in persistent motion are left alone, and the selection
+/// and Motion1 state are not touched
+pub fn handle_solo_hover_motion(
+    mut commands: Commands,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<crate::components::RightCamera>>,
+    particle_query: Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>, Has<InMotion>, Has<SoloMotion>), With<Particle>>,
+    motion1_state: Res<Motion1State>,
+    particle_radius: Res<ParticleRadius>,
+    cursor_state: Res<crate::plugins::viewport_constrained_camera::CameraViewportCursorState>,
+    mut hovered: Local<Option<Entity>>,
+) {
+    let ray = if motion1_state.solo_on_hover && cursor_state.is_cursor_in_viewport {
+        windows.single().ok()
+            .and_then(|window| window.cursor_position())
+            .zip(camera_query.single().ok())
+            .and_then(|(cursor_pos, (camera, camera_transform))| camera.viewport_to_world(camera_transform, cursor_pos).ok())
+    } else {
+        None
+    };
+    
+    // Closest visible particle under the cursor
+    let hit = ray.and_then(|ray| {
+        particle_query.iter()
+            .filter(|(_, _, visibility, ..)| **visibility != Visibility::Hidden)
+            .filter_map(|(entity, transform, _, shape, ..)| {
+                let hit_radius = particle_radius.0 * shape.copied().unwrap_or_default().bounding_radius_factor();
+                raycast_particle(&ray, transform, *ray.direction, hit_radius).map(|distance| (entity, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(entity, _)| entity)
+    });
+    
+    if hit == *hovered {
+        return;
+    }
+    
+    // Only the transient motion is removed; persistent motion added meanwhile drops the SoloMotion tag
+    if let Some(previous) = hovered.take()
+        && let Ok((.., true)) = particle_query.get(previous)
+    {
+        commands.entity(previous).remove::<(InMotion, SoloMotion)>();
+    }
+    if let Some(entity) = hit
+        && let Ok((.., false, _)) = particle_query.get(entity)
+    {
+        commands.entity(entity).insert((InMotion, SoloMotion));
+    }
+    *hovered = hit;
+}
+
 fn raycast_particle(
     ray: &bevy::math::Ray3d,
     transform: &Transform,
     ray_dir: Vec3,
+    particle_radius: f32,
 ) -> Option<f32> {
     let particle_pos = transform.translation;
     let to_particle = particle_pos - ray.origin;
@@ -123,7 +200,7 @@ fn raycast_particle(
     let distance_to_ray = (closest_point - particle_pos).length();
     
     // Check if ray intersects particle sphere
-    if distance_to_ray < PARTICLE_RADIUS {
+    if distance_to_ray < particle_radius {
         Some(projection)
     } else {
         None
@@ -132,22 +209,61 @@ fn raycast_particle(
 
 fn toggle_particle_selection(
     entity: Entity,
-    particle_query: &mut Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, Without<Selected>)>,
-    selected_query: &mut Query<(Entity, &Transform, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, With<Selected>)>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    particle_query: &Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, Without<Selected>)>,
+    selected_query: &Query<(Entity, &Transform, &Visibility, Option<&ParticleShape>), (With<Particle>, With<Selected>)>,
     commands: &mut Commands,
     selection_state: &mut ResMut<ParticleSelectionState>,
+    selection_changed: &mut MessageWriter<SelectionChanged>,
 ) {
-    if let Ok((_, _, mut material)) = selected_query.get_mut(entity) {
-        // Deselect: change to white
-        material.0 = materials.add(COLOR_WHITE);
+    // Selection is shown by an outline, the particle's own material stays untouched
+    if selected_query.contains(entity) {
         commands.entity(entity).remove::<Selected>();
         selection_state.selected_particles.remove(&entity);
-    } else if let Ok((_, _, mut material)) = particle_query.get_mut(entity) {
-                // Select: change to green
-                material.0 = materials.add(COLOR_GREEN);
+        selection_changed.write(SelectionChanged { added: Vec::new(), removed: vec![entity] });
+    } else if particle_query.contains(entity) {
         commands.entity(entity).insert(Selected);
         selection_state.selected_particles.insert(entity);
+        selection_changed.write(SelectionChanged { added: vec![entity], removed: Vec::new() });
+    }
+}
+
+/// System to fit the particle bounds (and optionally the grid) around every current particle
+/// Base positions are re-normalized against the new bounds, so no particle moves
+pub fn handle_fit_bounds_to_particles(
+    mut bounds_state: ResMut<ParticleBoundsState>,
+    mut grid_state: ResMut<GridState>,
+    mut particle_positions: ResMut<crate::components::ParticlePositions>,
+    group_state: Res<ParticleGroupState>,
+) {
+    if !bounds_state.fit_requested {
+        return;
+    }
+    bounds_state.fit_requested = false;
+    
+    // Empty scene: nothing to frame, keep the current bounds
+    if particle_positions.current_positions.is_empty() {
+        return;
+    }
+    
+    let (min, max) = particle_positions.current_positions.values()
+        .fold((Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)), |(min, max), pos| (min.min(*pos), max.max(*pos)));
+    bounds_state.min = min - Vec3::splat(BOUNDS_FIT_MARGIN);
+    bounds_state.max = max + Vec3::splat(BOUNDS_FIT_MARGIN);
+    // Particles already sit where they should, skip the reposition in update_particle_bounds
+    bounds_state.previous_min = bounds_state.min;
+    bounds_state.previous_max = bounds_state.max;
+    
+    let current: Vec<(Entity, Vec3)> = particle_positions.current_positions.iter().map(|(entity, pos)| (*entity, *pos)).collect();
+    for (entity, position) in current {
+        crate::systems::selection_transform::bake_base_position(entity, position, &mut particle_positions, &bounds_state, &group_state);
+    }
+    
+    // Grid is centered on its offset, so it has to reach the farthest bound on each side
+    if bounds_state.fit_grid {
+        let center = Vec3::new(grid_state.offset.x, 0.0, grid_state.offset.y);
+        let extent = (bounds_state.min - center).abs().max((bounds_state.max - center).abs());
+        grid_state.size_x = ((extent.x * 2.0).ceil() as i32).clamp(1, 100);
+        grid_state.size_z = ((extent.z * 2.0).ceil() as i32).clamp(1, 100);
     }
 }
 
@@ -157,36 +273,27 @@ pub fn update_particle_bounds(
     mut particle_positions: ResMut<crate::components::ParticlePositions>,
 ) {
     // Check if bounds changed
-    if bounds_state.bounds_x != bounds_state.previous_bounds_x ||
-       bounds_state.bounds_z != bounds_state.previous_bounds_z ||
-       bounds_state.bounds_y_height != bounds_state.previous_bounds_y_height {
-        
-        let bounds_y_min = 1.0;  // Always starts at 1.0
+    if bounds_state.min != bounds_state.previous_min ||
+       bounds_state.max != bounds_state.previous_max {
         
         // Update all particle positions based on new bounds
         for (entity, mut transform) in particle_query.iter_mut() {
             if let Some(base_pos) = particle_positions.base_positions.get(&entity) {
                 // Recalculate world position from normalized base position using new bounds
-                // bounds_x is now total size (diameter), so calculate: (normalized - 0.5) * total_size
-                let x = (base_pos.x - 0.5) * bounds_state.bounds_x;
-                let z = (base_pos.z - 0.5) * bounds_state.bounds_z;
-                let y = bounds_y_min + base_pos.y * bounds_state.bounds_y_height;
-                
-                let new_position = Vec3::new(x, y, z);
+                let new_position = bounds_state.normalized_to_world(*base_pos);
                 transform.translation = new_position;
                 particle_positions.current_positions.insert(entity, new_position);
             }
         }
         
         // Update previous values
-        bounds_state.previous_bounds_x = bounds_state.bounds_x;
-        bounds_state.previous_bounds_z = bounds_state.bounds_z;
-        bounds_state.previous_bounds_y_height = bounds_state.bounds_y_height;
+        bounds_state.previous_min = bounds_state.min;
+        bounds_state.previous_max = bounds_state.max;
     }
 }
 
 pub fn update_particle_group_transform(
-    mut particle_query: Query<(Entity, &mut Transform), With<Particle>>,
+    mut particle_query: Query<(Entity, &mut Transform), (With<Particle>, Without<Locked>)>,
     mut group_state: ResMut<ParticleGroupState>,
     mut particle_positions: ResMut<crate::components::ParticlePositions>,
     bounds_state: Res<ParticleBoundsState>,
@@ -199,20 +306,14 @@ pub fn update_particle_group_transform(
         group_state.previous_offset = group_state.offset;
         group_state.previous_scale = group_state.scale;
         
-    // Apply group transform (offset and scale) to all particles
+    // Apply group transform (offset and scale) to all unlocked particles
     // Calculate base positions from normalized positions and current bounds
-    let bounds_y_min = 1.0;  // Always starts at 1.0
-    
     for (entity, mut transform) in particle_query.iter_mut() {
         if let Some(base_pos) = particle_positions.base_positions.get(&entity) {
             // Calculate base world position from normalized position
-            // bounds_x is now total size (diameter), so calculate: (normalized - 0.5) * total_size
-            let base_x = (base_pos.x - 0.5) * bounds_state.bounds_x;
-            let base_z = (base_pos.z - 0.5) * bounds_state.bounds_z;
-            let base_y = bounds_y_min + base_pos.y * bounds_state.bounds_y_height;
-                let base_world_pos = Vec3::new(base_x, base_y, base_z);
+                let base_world_pos = bounds_state.normalized_to_world(*base_pos);
                 
-                // Apply group transform: (base_pos * scale) + offset
+                // Apply group transform: (base_pos * scale) + offset, scale applied per axis
                 let final_position = base_world_pos * group_state.scale + group_state.offset;
                 transform.translation = final_position;
                 particle_positions.current_positions.insert(entity, final_position);
@@ -220,3 +321,178 @@ pub fn update_particle_group_transform(
         }
     }
 }
+
+/// System to rescale all particles when the global particle radius changes
+pub fn update_particle_radius(
+    particle_radius: Res<ParticleRadius>,
+    mut particle_query: Query<&mut Transform, With<Particle>>,
+) {
+    if !particle_radius.is_changed() {
+        return;
+    }
+    
+    let scale = particle_radius.mesh_scale();
+    for mut transform in particle_query.iter_mut() {
+        transform.scale = scale;
+    }
+}
+
+/// Result of pressing the Motion 1 button: particles to start, particles to stop and whether Motion1 stays active
+/// With a selection each selected particle flips (others keep moving); without one, all particles stop
+/// if all were moving, otherwise the ones at rest start. `selected` and `all` keep their order in `to_add`,
+/// so staggered phases follow it
+pub fn compute_motion_toggle(
+    selected: &[Entity],
+    in_motion: &std::collections::HashSet<Entity>,
+    all: &[Entity],
+) -> (Vec<Entity>, Vec<Entity>, bool) {
+    if !selected.is_empty() {
+        let (to_remove, to_add): (Vec<Entity>, Vec<Entity>) = selected.iter()
+            .partition(|entity| in_motion.contains(entity));
+        // Unselected particles in motion keep it active
+        let unselected_in_motion = in_motion.len() - to_remove.len();
+        let is_active = unselected_in_motion + to_add.len() > 0;
+        return (to_add, to_remove, is_active);
+    }
+    
+    if in_motion.len() == all.len() {
+        (Vec::new(), all.to_vec(), false)
+    } else {
+        let to_add = all.iter()
+            .filter(|entity| !in_motion.contains(entity))
+            .copied()
+            .collect();
+        (to_add, Vec::new(), true)
+    }
+}
+
+/// System to tint locked particles and restore their base color when they are unlocked
+pub fn update_locked_particle_tint(
+    mut locked_query: Query<&mut MeshMaterial3d<StandardMaterial>, (With<Particle>, With<Locked>)>,
+    mut unlocked_query: Query<(&mut MeshMaterial3d<StandardMaterial>, &ParticleBaseColor), (With<Particle>, Without<Locked>)>,
+    mut removed_locks: RemovedComponents<Locked>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut locked_material: Local<Option<Handle<StandardMaterial>>>,
+) {
+    let locked_handle = locked_material.get_or_insert_with(|| materials.add(COLOR_LOCKED)).clone();
+    
+    for mut material in locked_query.iter_mut() {
+        if material.0 != locked_handle {
+            material.0 = locked_handle.clone();
+        }
+    }
+    
+    for entity in removed_locks.read() {
+        if let Ok((mut material, base_color)) = unlocked_query.get_mut(entity) {
+            material.0 = base_color.0.clone();
+        }
+    }
+}
+
+/// System to tint particles in Motion1 and restore their base color when the motion stops
+/// The tint is an emissive copy of each base material (shared by all particles with that base);
+/// only particles showing their base material are tinted, so other tints (locked, creation pulse,
+/// lifetime fade, color-by) keep precedence and the selection outline is unaffected
+pub fn update_motion_tint(
+    motion1_state: Res<Motion1State>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut particle_query: Query<(&mut MeshMaterial3d<StandardMaterial>, &ParticleBaseColor, Has<InMotion>), With<Particle>>,
+    mut tint_materials: Local<std::collections::HashMap<AssetId<StandardMaterial>, Handle<StandardMaterial>>>,
+) {
+    if !motion1_state.tint_in_motion && tint_materials.is_empty() {
+        return;
+    }
+    
+    for (mut material, base_color, in_motion) in particle_query.iter_mut() {
+        let base_id = base_color.0.id();
+        let is_tinted = tint_materials.get(&base_id).is_some_and(|tint| material.0 == *tint);
+        
+        if !(motion1_state.tint_in_motion && in_motion) {
+            if is_tinted {
+                material.0 = base_color.0.clone();
+            }
+            continue;
+        }
+        
+        if material.0 != base_color.0 {
+            continue;
+        }
+        let tint_handle = tint_materials.entry(base_id).or_insert_with(|| {
+            let mut tint_material = materials.get(&base_color.0).cloned().unwrap_or_default();
+            tint_material.emissive = MOTION_TINT_EMISSIVE;
+            materials.add(tint_material)
+        });
+        material.0 = tint_handle.clone();
+    }
+    
+    // Everything is restored once the tint is off; dropping the handles frees the copies
+    if !motion1_state.tint_in_motion {
+        tint_materials.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use crate::systems::test_harness::*;
+    
+    fn entities(count: usize) -> Vec<Entity> {
+        let mut world = World::new();
+        (0..count).map(|_| world.spawn_empty().id()).collect()
+    }
+    
+    #[test]
+    fn motion_toggle_flips_each_selected_particle() {
+        let all = entities(4);
+        let in_motion: HashSet<Entity> = [all[0], all[2]].into_iter().collect();
+        
+        // all[0] stops, all[1] starts, unselected all[2] keeps moving
+        let (to_add, to_remove, is_active) = compute_motion_toggle(&all[..2], &in_motion, &all);
+        assert_eq!(to_add, vec![all[1]]);
+        assert_eq!(to_remove, vec![all[0]]);
+        assert!(is_active);
+        
+        // Stopping the only moving particle deactivates Motion1
+        let in_motion: HashSet<Entity> = [all[0]].into_iter().collect();
+        let (to_add, to_remove, is_active) = compute_motion_toggle(&all[..1], &in_motion, &all);
+        assert!(to_add.is_empty());
+        assert_eq!(to_remove, vec![all[0]]);
+        assert!(!is_active);
+    }
+    
+    #[test]
+    fn motion_toggle_without_selection_stops_all_when_all_move() {
+        let all = entities(3);
+        let in_motion: HashSet<Entity> = all.iter().copied().collect();
+        
+        let (to_add, to_remove, is_active) = compute_motion_toggle(&[], &in_motion, &all);
+        assert!(to_add.is_empty());
+        assert_eq!(to_remove, all);
+        assert!(!is_active);
+    }
+    
+    #[test]
+    fn motion_toggle_without_selection_starts_all_when_none_move() {
+        let all = entities(3);
+        
+        let (to_add, to_remove, is_active) = compute_motion_toggle(&[], &HashSet::new(), &all);
+        assert_eq!(to_add, all);
+        assert!(to_remove.is_empty());
+        assert!(is_active);
+    }
+    
+    #[test]
+    fn locked_particles_stay_put_under_a_group_offset() {
+        let mut app = test_app();
+        app.add_systems(Update, update_particle_group_transform);
+        let particles = spawn_particles(&mut app, &[Vec3::new(-1.0, 1.5, 0.0), Vec3::new(1.0, 1.5, 0.0)]);
+        app.world_mut().entity_mut(particles[0]).insert(Locked);
+        
+        app.world_mut().resource_mut::<ParticleGroupState>().offset = Vec3::new(1.0, 0.0, 2.0);
+        run_updates(&mut app, 2);
+        
+        assert_near(position(&app, particles[0]), Vec3::new(-1.0, 1.5, 0.0));
+        assert_near(position(&app, particles[1]), Vec3::new(2.0, 1.5, 2.0));
+    }
+}
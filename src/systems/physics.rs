@@ -0,0 +1,120 @@
+// systems/physics.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Particle, Velocity, VelocityArrowState, AttractorState, AttractorFalloff, Locked, ParticlePositions, ParticleSelectionState, SelectionTransformState, ParticleBoundsState, ParticleGroupState};
+use crate::constants::{VELOCITY_ARROW_COLOR, VELOCITY_ARROW_MIN_SPEED, ATTRACTOR_COLOR, ATTRACTOR_MIN_DISTANCE};
+use crate::systems::selection_transform::{shift_selection_original, bake_base_position};
+
+/// System to draw velocity vectors as arrows from each moving particle
+/// Uses immediate-mode gizmos, so nothing needs to be spawned or despawned
+pub fn draw_velocity_arrows(
+    mut gizmos: Gizmos,
+    arrow_state: Res<VelocityArrowState>,
+    particle_query: Query<(&Transform, &Velocity, &Visibility), With<Particle>>,
+) {
+    if !arrow_state.is_visible {
+        return;
+    }
+    
+    for (transform, velocity, visibility) in particle_query.iter() {
+        // Skip near-zero velocities and hidden particles to avoid clutter
+        if velocity.0.length() < VELOCITY_ARROW_MIN_SPEED || *visibility == Visibility::Hidden {
+            continue;
+        }
+        
+        let start = transform.translation;
+        let end = start + velocity.0 * arrow_state.length_scale;
+        gizmos.arrow(start, end, VELOCITY_ARROW_COLOR);
+    }
+}
+
+/// System to pull selected particles toward the attractor (or push them away)
+/// Integrates each particle's Velocity
+pub fn apply_attractor_force(
+    mut commands: Commands,
+    time: Res<Time>,
+    attractor_state: Res<AttractorState>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut particle_query: Query<(&mut Transform, Option<&mut Velocity>), (With<Particle>, Without<Locked>)>,
+) {
+    if !attractor_state.is_active {
+        return;
+    }
+    
+    let delta_time = time.delta_secs();
+    let sign = if attractor_state.repel { -1.0 } else { 1.0 };
+    
+    for entity in selection_state.selected_particles.iter() {
+        let Ok((mut transform, velocity)) = particle_query.get_mut(*entity) else { continue };
+        
+        let to_attractor = attractor_state.position - transform.translation;
+        let distance = to_attractor.length();
+        let direction = to_attractor.normalize_or_zero();
+        let magnitude = match attractor_state.falloff {
+            AttractorFalloff::InverseDistance => attractor_state.strength / distance.max(ATTRACTOR_MIN_DISTANCE),
+            AttractorFalloff::Linear => attractor_state.strength * distance,
+        };
+        
+        // Clamp speed to keep the integration stable near the attractor
+        let previous_velocity = velocity.as_ref().map(|velocity| velocity.0).unwrap_or(Vec3::ZERO);
+        let new_velocity = (previous_velocity + direction * magnitude * sign * delta_time)
+            .clamp_length_max(attractor_state.max_speed);
+        match velocity {
+            Some(mut velocity) => velocity.0 = new_velocity,
+            None => {
+                commands.entity(*entity).insert(Velocity(new_velocity));
+            }
+        }
+        
+        let step = new_velocity * delta_time;
+        transform.translation += step;
+        // Base positions follow, so bounds and group changes keep the pulled position
+        bake_base_position(*entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+        shift_selection_original(&mut transform_state, *entity, step);
+    }
+}
+
+/// System to mark the attractor position while the force is active
+pub fn draw_attractor(
+    mut gizmos: Gizmos,
+    attractor_state: Res<AttractorState>,
+) {
+    if !attractor_state.is_active {
+        return;
+    }
+    
+    gizmos.sphere(Isometry3d::from_translation(attractor_state.position), 0.1, ATTRACTOR_COLOR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_harness::*;
+    
+    #[test]
+    fn attractor_pulls_a_selected_particle_toward_it() {
+        let mut app = test_app();
+        app.add_systems(Update, apply_attractor_force);
+        {
+            let mut attractor_state = app.world_mut().resource_mut::<AttractorState>();
+            attractor_state.is_active = true;
+            attractor_state.position = Vec3::new(0.0, 1.5, 0.0);
+            attractor_state.strength = 5.0;
+        }
+        let start = Vec3::new(1.0, 1.5, 0.0);
+        let particles = spawn_particles(&mut app, &[start]);
+        set_selection(&mut app, &particles);
+        
+        run_updates(&mut app, 10);
+        
+        // Moved straight along the line to the attractor, without overshooting it
+        let moved = position(&app, particles[0]);
+        assert!(moved.x < start.x && moved.x > 0.0, "particle at {moved}");
+        assert!((moved.y - 1.5).abs() < 1e-5 && moved.z.abs() < 1e-5);
+    }
+}
@@ -0,0 +1,142 @@
+// systems/playback.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Particle, ParticlePositions, PlaybackState, PlaybackMode, PlaybackFrame};
+
+/// Run condition for the motion systems: live simulation pauses while a recording is replayed
+pub fn live_simulation(playback_state: Res<PlaybackState>) -> bool {
+    playback_state.mode != PlaybackMode::Replaying
+}
+
+/// Write recorded or restored positions onto the particles (particles despawned since are skipped)
+fn set_particle_positions(
+    positions: &[(Entity, Vec3)],
+    particle_query: &mut Query<&mut Transform, With<Particle>>,
+    particle_positions: &mut ParticlePositions,
+) {
+    for &(entity, position) in positions {
+        if let Ok(mut transform) = particle_query.get_mut(entity) {
+            transform.translation = position;
+            particle_positions.current_positions.insert(entity, position);
+        }
+    }
+}
+
+/// System to handle the record, stop, replay and exit requests from the UI
+/// Entering replay keeps the live positions, exiting puts the particles back there so live motion resumes where it paused
+pub fn handle_playback_requests(
+    mut playback_state: ResMut<PlaybackState>,
+    mut particle_query: Query<&mut Transform, With<Particle>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+) {
+    if playback_state.record_requested {
+        playback_state.record_requested = false;
+        if playback_state.mode == PlaybackMode::Live {
+            playback_state.frames.clear();
+            playback_state.recorded_positions = 0;
+            playback_state.mode = PlaybackMode::Recording;
+        }
+    }
+    
+    if playback_state.stop_requested {
+        playback_state.stop_requested = false;
+        if playback_state.mode == PlaybackMode::Recording {
+            playback_state.mode = PlaybackMode::Live;
+        }
+    }
+    
+    if playback_state.replay_requested {
+        playback_state.replay_requested = false;
+        if playback_state.mode == PlaybackMode::Live && !playback_state.frames.is_empty() {
+            playback_state.live_positions = particle_positions.current_positions.keys()
+                .filter_map(|entity| particle_query.get(*entity).ok().map(|transform| (*entity, transform.translation)))
+                .collect();
+            playback_state.mode = PlaybackMode::Replaying;
+            playback_state.playhead = 0.0;
+            playback_state.is_playing = true;
+        }
+    }
+    
+    if playback_state.exit_replay_requested {
+        playback_state.exit_replay_requested = false;
+        if playback_state.mode == PlaybackMode::Replaying {
+            let live_positions = std::mem::take(&mut playback_state.live_positions);
+            set_particle_positions(&live_positions, &mut particle_query, &mut particle_positions);
+            playback_state.mode = PlaybackMode::Live;
+            playback_state.is_playing = false;
+        }
+    }
+}
+
+/// System to record every particle's position after this frame's motion
+/// Recording stops on its own after the configured duration, frame cap or position budget
+pub fn record_playback_frame(
+    time: Res<Time>,
+    mut playback_state: ResMut<PlaybackState>,
+    particle_query: Query<(Entity, &Transform), With<Particle>>,
+    // Frames since the recording started and their elapsed seconds
+    mut clock: Local<(u32, f32)>,
+) {
+    if playback_state.mode != PlaybackMode::Recording {
+        return;
+    }
+    
+    // The first frame of a recording starts the clock at zero
+    if playback_state.frames.is_empty() {
+        *clock = (0, 0.0);
+    } else {
+        clock.0 += 1;
+        clock.1 += time.delta_secs();
+    }
+    
+    let (frame_count, elapsed) = *clock;
+    if frame_count.is_multiple_of(playback_state.sample_interval.max(1)) {
+        // A frame that would go over the position budget ends the recording instead
+        let particle_count = particle_query.iter().len();
+        if playback_state.recorded_positions + particle_count > playback_state.max_positions {
+            playback_state.mode = PlaybackMode::Live;
+            return;
+        }
+        playback_state.frames.push(PlaybackFrame {
+            time: elapsed,
+            positions: particle_query.iter()
+                .map(|(entity, transform)| (entity, transform.translation))
+                .collect(),
+        });
+        playback_state.recorded_positions += particle_count;
+    }
+    
+    if elapsed >= playback_state.duration || playback_state.frames.len() >= playback_state.max_frames {
+        playback_state.mode = PlaybackMode::Live;
+    }
+}
+
+/// System to show the recorded frame at the playhead, advancing it while playing
+/// Written every frame, since the selection transform keeps re-applying its positions
+pub fn apply_playback_frame(
+    time: Res<Time>,
+    mut playback_state: ResMut<PlaybackState>,
+    mut particle_query: Query<&mut Transform, With<Particle>>,
+    mut particle_positions: ResMut<ParticlePositions>,
+) {
+    if playback_state.mode != PlaybackMode::Replaying {
+        return;
+    }
+    
+    // Playing stops at the end of the recording (the scrubber can still move back)
+    if playback_state.is_playing {
+        let recorded_seconds = playback_state.recorded_seconds();
+        playback_state.playhead = (playback_state.playhead + time.delta_secs()).min(recorded_seconds);
+        if playback_state.playhead >= recorded_seconds {
+            playback_state.is_playing = false;
+        }
+    }
+    
+    // Latest frame at or before the playhead
+    let playhead = playback_state.playhead;
+    let index = playback_state.frames.partition_point(|frame| frame.time <= playhead).saturating_sub(1);
+    if let Some(frame) = playback_state.frames.get(index) {
+        set_particle_positions(&frame.positions, &mut particle_query, &mut particle_positions);
+    }
+}
@@ -0,0 +1,54 @@
+// systems/proximity.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{ProximityLinesState, SpatialGrid};
+
+/// System to draw a line between every pair of particles closer than the threshold
+/// Runs after the spatial grid rebuild, so the lines follow this frame's positions
+/// Stops at max_edges and flags the cap so the UI can warn about it
+pub fn draw_proximity_lines(
+    mut gizmos: Gizmos,
+    mut proximity_state: ResMut<ProximityLinesState>,
+    spatial_grid: Res<SpatialGrid>,
+) {
+    if !proximity_state.is_visible {
+        if proximity_state.capped {
+            proximity_state.capped = false;
+        }
+        return;
+    }
+    
+    let threshold = proximity_state.threshold;
+    let max_edges = proximity_state.max_edges;
+    let color = proximity_state.color;
+    let mut edges = 0;
+    let mut capped = false;
+    
+    'cells: for cell in spatial_grid.cells.values() {
+        for &(entity, position) in cell {
+            spatial_grid.for_each_within(position, threshold, |other, other_position| {
+                // Each pair once (and never a particle with itself)
+                if other <= entity || capped {
+                    return;
+                }
+                if edges >= max_edges {
+                    capped = true;
+                    return;
+                }
+                gizmos.line(position, other_position, color);
+                edges += 1;
+            });
+            if capped {
+                break 'cells;
+            }
+        }
+    }
+    
+    if proximity_state.capped != capped {
+        proximity_state.capped = capped;
+        if capped {
+            warn!("Proximity lines capped at {} edges", max_edges);
+        }
+    }
+}
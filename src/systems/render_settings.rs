@@ -0,0 +1,54 @@
+// systems/render_settings.rs
+// Copyright (C) 2026 vecnode
+
+use bevy::prelude::*;
+use crate::components::{Particle, ParticleBaseColor, RenderSettingsState};
+
+/// System to apply the MSAA setting to the cameras at runtime
+/// All cameras share the window target, so they are kept at the same sample count
+pub fn apply_render_settings(
+    render_settings: Res<RenderSettingsState>,
+    mut camera_query: Query<&mut Msaa, With<Camera>>,
+) {
+    if !render_settings.is_changed() {
+        return;
+    }
+    
+    for mut msaa in camera_query.iter_mut() {
+        if *msaa != render_settings.msaa {
+            *msaa = render_settings.msaa;
+        }
+    }
+}
+
+/// Emissive color that makes a material glow with its own base color at the given intensity
+pub fn glow_emissive(base_color: Color, intensity: f32) -> LinearRgba {
+    let linear = base_color.to_linear();
+    LinearRgba::rgb(linear.red, linear.green, linear.blue) * intensity
+}
+
+/// System to apply the particle glow to the particles' base materials
+/// Runs for every base material when the setting changes and for new particles otherwise;
+/// the tint and pulse copies set their own emissive, and the selection shell is unlit, so it stays readable
+pub fn apply_particle_glow(
+    render_settings: Res<RenderSettingsState>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    base_query: Query<Ref<ParticleBaseColor>, With<Particle>>,
+) {
+    let glow_changed = render_settings.is_changed();
+    let mut updated = std::collections::HashSet::new();
+    for base_color in base_query.iter() {
+        if !(glow_changed || base_color.is_added()) || !updated.insert(base_color.0.id()) {
+            continue;
+        }
+        // Materials are only touched when the glow differs, so unchanged assets are not re-uploaded
+        let Some(material) = materials.get(&base_color.0) else {
+            continue;
+        };
+        let emissive = glow_emissive(material.base_color, render_settings.particle_glow);
+        if material.emissive != emissive
+            && let Some(material) = materials.get_mut(&base_color.0) {
+            material.emissive = emissive;
+        }
+    }
+}
@@ -2,8 +2,8 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{SelectionBox, SelectionBoxState, Particle, Selected, ParticleSelectionState, MouseButtonState};
-use crate::constants::{SELECTION_BOX_COLOR, COLOR_GREEN, COLOR_WHITE};
+use crate::components::{SelectionBox, SelectionBoxLabel, SelectionBoxState, Particle, Selected, ParticleSelectionState, MouseButtonState, SelectionOutline, SelectionStyle, ParticleShape, BrushSelectState, ParticlePositions, SelectionChanged, SelectionGrowState, SpatialGrid};
+use crate::constants::{SELECTION_BOX_COLOR, SELECTION_BOX_LABEL_FONT_SIZE, SELECTION_BOX_LABEL_OFFSET, MIN_DRAG_DISTANCE, PARTICLE_RADIUS, BRUSH_COLOR};
 
 pub fn handle_right_mouse_button(
     windows: Query<&Window>,
@@ -44,11 +44,40 @@ pub fn handle_right_mouse_button(
     // to ensure releases are processed even when cursor is over Egui panels
 }
 
+/// Startup system spawning the selection box node and its size label once, hidden until a drag starts
+pub fn spawn_selection_box(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(0.0),
+            height: Val::Px(0.0),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(SELECTION_BOX_COLOR),
+        Visibility::Hidden,
+        SelectionBox,
+    ));
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: SELECTION_BOX_LABEL_FONT_SIZE,
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        Visibility::Hidden,
+        SelectionBoxLabel,
+    ));
+}
+
+/// System to move and resize the selection box node during a drag (no spawning or despawning)
 pub fn update_selection_box_visual(
     windows: Query<&Window>,
     mut selection_box_state: ResMut<SelectionBoxState>,
-    mut selection_box_query: Query<(Entity, &mut Node), With<SelectionBox>>,
-    mut commands: Commands,
+    mut selection_box_query: Query<(&mut Node, &mut Visibility), With<SelectionBox>>,
 ) {
     let Ok(window) = windows.single() else { return };
     
@@ -58,78 +87,156 @@ pub fn update_selection_box_visual(
         }
         
         if let (Some(start), Some(current)) = (selection_box_state.start_position, selection_box_state.current_position) {
-            let left = start.x.min(current.x);
-            let top = start.y.min(current.y);
             let width = (current.x - start.x).abs();
             let height = (current.y - start.y).abs();
+            let visible = width > 1.0 && height > 1.0;
             
-            if width > 1.0 && height > 1.0 {
-                if selection_box_query.is_empty() {
-                    commands.spawn((
-                        Node {
-                            position_type: PositionType::Absolute,
-                            left: Val::Px(left),
-                            top: Val::Px(top),
-                            width: Val::Px(width),
-                            height: Val::Px(height),
-                            border: UiRect::all(Val::Px(1.0)),
-                            ..default()
-                        },
-                        BackgroundColor(SELECTION_BOX_COLOR),
-                        SelectionBox,
-                    ));
-                } else {
-                    for (_, mut node) in selection_box_query.iter_mut() {
-                        node.left = Val::Px(left);
-                        node.top = Val::Px(top);
-                        node.width = Val::Px(width);
-                        node.height = Val::Px(height);
-                    }
-                }
+            for (mut node, mut visibility) in selection_box_query.iter_mut() {
+                node.left = Val::Px(start.x.min(current.x));
+                node.top = Val::Px(start.y.min(current.y));
+                node.width = Val::Px(width);
+                node.height = Val::Px(height);
+                visibility.set_if_neq(if visible { Visibility::Inherited } else { Visibility::Hidden });
             }
         }
     } else {
-        for (entity, _) in selection_box_query.iter() {
-            commands.entity(entity).despawn();
+        // Collapse and hide on release so the next drag never shows the old rectangle
+        for (mut node, mut visibility) in selection_box_query.iter_mut() {
+            if *visibility != Visibility::Hidden {
+                node.width = Val::Px(0.0);
+                node.height = Val::Px(0.0);
+                *visibility = Visibility::Hidden;
+            }
         }
     }
 }
 
-pub fn process_selection_box(
-    mut selection_box_state: ResMut<SelectionBoxState>,
-    windows: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
-    particle_query: Query<(Entity, &Transform), With<Particle>>,
-    mut selected_query: Query<(Entity, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, With<Selected>)>,
-    mut unselected_query: Query<(Entity, &mut MeshMaterial3d<StandardMaterial>), (With<Particle>, Without<Selected>)>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+/// System to deselect every particle when the Clear Selection button was pressed
+/// Outlines, trajectory circles and the bounding box follow through their reactive systems
+pub fn handle_clear_selection(
     mut commands: Commands,
     mut particle_selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
 ) {
-    if selection_box_state.is_active {
+    if !particle_selection_state.clear_requested {
         return;
     }
+    particle_selection_state.clear_requested = false;
     
-    let (Some(start), Some(end)) = (selection_box_state.start_position.take(), selection_box_state.current_position.take()) else {
+    let removed: Vec<Entity> = particle_selection_state.selected_particles.drain().collect();
+    for entity in removed.iter() {
+        commands.entity(*entity).remove::<Selected>();
+    }
+    if !removed.is_empty() {
+        selection_changed.write(SelectionChanged { added: Vec::new(), removed });
+    }
+}
+
+/// System to show the selection box size in pixels next to the cursor while dragging
+/// With the count preview enabled the label also lists how many particles the box would select
+pub fn update_selection_box_label(
+    windows: Query<&Window>,
+    selection_box_state: Res<SelectionBoxState>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    particle_query: Query<(Entity, &Transform, &Visibility), With<Particle>>,
+    mut label_query: Query<(&mut Text, &mut Node, &mut Visibility), (With<SelectionBoxLabel>, Without<Particle>)>,
+) {
+    let Ok((mut text, mut node, mut visibility)) = label_query.single_mut() else { return };
+    
+    let drag = match (selection_box_state.is_active, selection_box_state.start_position, selection_box_state.current_position) {
+        (true, Some(start), Some(current)) => Some((start, current)),
+        _ => None,
+    };
+    let Some((start, current)) = drag.filter(|(start, current)| (*current - *start).abs().cmpgt(Vec2::ONE).all()) else {
+        visibility.set_if_neq(Visibility::Hidden);
         return;
     };
+    let Ok(window) = windows.single() else { return };
     
-    let drag_distance = (end - start).length();
-    const MIN_DRAG_DISTANCE: f32 = 5.0;
+    let size = (current - start).abs();
+    let mut label = format!("{:.0} x {:.0} px", size.x, size.y);
+    if selection_box_state.show_count_preview {
+        let count = particles_in_selection_box(start, current, window, &camera_query, &particle_query).len();
+        label.push_str(&format!(" ({} particles)", count));
+    }
     
-    if drag_distance < MIN_DRAG_DISTANCE {
-        for entity in particle_selection_state.selected_particles.clone() {
-            if let Ok((_, mut material)) = selected_query.get_mut(entity) {
-                material.0 = materials.add(COLOR_WHITE);
-                commands.entity(entity).remove::<Selected>();
-                particle_selection_state.selected_particles.remove(&entity);
+    text.0 = label;
+    node.left = Val::Px(current.x + SELECTION_BOX_LABEL_OFFSET.x);
+    node.top = Val::Px(current.y + SELECTION_BOX_LABEL_OFFSET.y);
+    visibility.set_if_neq(Visibility::Inherited);
+}
+
+/// System to grow or shrink the selection by proximity, using the spatial grid for neighbors
+/// Grow selects visible unselected particles near the selection; shrink deselects its boundary
+pub fn handle_selection_grow_shrink(
+    mut commands: Commands,
+    mut grow_state: ResMut<SelectionGrowState>,
+    mut particle_selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+    spatial_grid: Res<SpatialGrid>,
+    particle_positions: Res<ParticlePositions>,
+    visibility_query: Query<&Visibility, With<Particle>>,
+) {
+    if !grow_state.grow_requested && !grow_state.shrink_requested {
+        return;
+    }
+    let grow = grow_state.grow_requested;
+    grow_state.grow_requested = false;
+    grow_state.shrink_requested = false;
+    
+    let radius = grow_state.radius;
+    let selected = &particle_selection_state.selected_particles;
+    let is_visible = |entity: Entity| visibility_query.get(entity).is_ok_and(|visibility| *visibility != Visibility::Hidden);
+    
+    let mut changed: Vec<Entity> = Vec::new();
+    for entity in selected.iter() {
+        let Some(&position) = particle_positions.current_positions.get(entity) else { continue };
+        let mut on_boundary = false;
+        spatial_grid.for_each_within(position, radius, |other, _| {
+            if selected.contains(&other) || !is_visible(other) {
+                return;
             }
+            if grow {
+                changed.push(other);
+            } else {
+                on_boundary = true;
+            }
+        });
+        if on_boundary {
+            changed.push(*entity);
         }
+    }
+    changed.sort();
+    changed.dedup();
+    if changed.is_empty() {
         return;
     }
     
-    let Ok(window) = windows.single() else { return };
-    
+    for entity in changed.iter() {
+        if grow {
+            commands.entity(*entity).insert(Selected);
+            particle_selection_state.selected_particles.insert(*entity);
+        } else {
+            commands.entity(*entity).remove::<Selected>();
+            particle_selection_state.selected_particles.remove(entity);
+        }
+    }
+    if grow {
+        selection_changed.write(SelectionChanged { added: changed, removed: Vec::new() });
+    } else {
+        selection_changed.write(SelectionChanged { added: Vec::new(), removed: changed });
+    }
+}
+
+/// Visible particles whose screen position falls inside the box spanned by two logical cursor positions
+/// Shared by the box selection on release and the live count preview while dragging
+fn particles_in_selection_box(
+    start: Vec2,
+    end: Vec2,
+    window: &Window,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    particle_query: &Query<(Entity, &Transform, &Visibility), With<Particle>>,
+) -> Vec<Entity> {
     // Find camera whose viewport contains the selection box center
     let box_center = (start + end) * 0.5;
     let cursor_physical = box_center * window.scale_factor() as f32;
@@ -151,12 +258,14 @@ pub fn process_selection_box(
         }
     }
     
-    let Some((camera, camera_transform)) = selected_camera else { return };
+    let Some((camera, camera_transform)) = selected_camera else { return Vec::new() };
     
     // Get viewport information for coordinate conversion
-    let viewport = camera.viewport.as_ref().expect("Camera should have viewport");
-    let viewport_physical_start = viewport.physical_position.as_vec2();
-    let viewport_physical_size = viewport.physical_size.as_vec2();
+    // A camera without a viewport (e.g. early frames before it is assigned) renders to the full window
+    let (viewport_physical_start, viewport_physical_size) = match camera.viewport.as_ref() {
+        Some(viewport) => (viewport.physical_position.as_vec2(), viewport.physical_size.as_vec2()),
+        None => (Vec2::ZERO, window.physical_size().as_vec2()),
+    };
     let scale_factor = window.scale_factor() as f32;
     
     // Convert selection box coordinates from logical to physical, then to viewport-relative
@@ -169,7 +278,13 @@ pub fn process_selection_box(
     let top_physical = (start_physical.y.min(end_physical.y) - viewport_physical_start.y).max(0.0);
     let bottom_physical = (start_physical.y.max(end_physical.y) - viewport_physical_start.y).min(viewport_physical_size.y);
     
-    for (entity, transform) in particle_query.iter() {
+    let mut inside = Vec::new();
+    for (entity, transform, visibility) in particle_query.iter() {
+        // Hidden particles cannot be box-selected
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+        
         let world_pos = transform.translation;
         
         let Some(ndc) = camera.world_to_ndc(camera_transform, world_pos) else { continue };
@@ -177,19 +292,309 @@ pub fn process_selection_box(
         // Convert NDC to viewport-relative screen coordinates
         // NDC: -1 to 1, where (0,0) is center, (-1,-1) is bottom-left, (1,1) is top-right
         // Screen: 0 to viewport_size, where (0,0) is top-left
+        // world_to_ndc already applies the camera orientation, so this flip is the same for
+        // every view (front, top or free); which world axis is "up" only depends on the camera
         let screen_x = (ndc.x * 0.5 + 0.5) * viewport_physical_size.x;
         let screen_y = (1.0 - (ndc.y * 0.5 + 0.5)) * viewport_physical_size.y;
         
         // Check if particle is within selection box (in viewport coordinates)
         if screen_x >= left_physical && screen_x <= right_physical &&
            screen_y >= top_physical && screen_y <= bottom_physical {
-            if !particle_selection_state.selected_particles.contains(&entity) {
-                if let Ok((_, mut material)) = unselected_query.get_mut(entity) {
-                    material.0 = materials.add(COLOR_GREEN);
-                    commands.entity(entity).insert(Selected);
-                    particle_selection_state.selected_particles.insert(entity);
-                }
+            inside.push(entity);
+        }
+    }
+    
+    inside
+}
+
+pub fn process_selection_box(
+    mut selection_box_state: ResMut<SelectionBoxState>,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    particle_query: Query<(Entity, &Transform, &Visibility), With<Particle>>,
+    mut commands: Commands,
+    mut particle_selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+) {
+    if selection_box_state.is_active {
+        return;
+    }
+    
+    let (Some(start), Some(end)) = (selection_box_state.start_position.take(), selection_box_state.current_position.take()) else {
+        return;
+    };
+    
+    let drag_distance = (end - start).length();
+    
+    if drag_distance < MIN_DRAG_DISTANCE {
+        let removed: Vec<Entity> = particle_selection_state.selected_particles.drain().collect();
+        for entity in removed.iter() {
+            commands.entity(*entity).remove::<Selected>();
+        }
+        if !removed.is_empty() {
+            selection_changed.write(SelectionChanged { added: Vec::new(), removed });
+        }
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    
+    let mut added = Vec::new();
+    for entity in particles_in_selection_box(start, end, window, &camera_query, &particle_query) {
+        if !particle_selection_state.selected_particles.contains(&entity) {
+            commands.entity(entity).insert(Selected);
+            particle_selection_state.selected_particles.insert(entity);
+            added.push(entity);
+        }
+    }
+    
+    if !added.is_empty() {
+        selection_changed.write(SelectionChanged { added, removed: Vec::new() });
+    }
+}
+
+/// System to show an outline shell around selected particles
+/// The shell is a slightly larger back-face sphere parented to the particle, so it follows
+/// its transform and visibility and is despawned together with it
+pub fn update_selection_outlines(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    selection_style: Res<SelectionStyle>,
+    added_query: Query<(Entity, Option<&ParticleShape>), (With<Particle>, Added<Selected>)>,
+    shape_query: Query<&ParticleShape>,
+    mut removed_selections: RemovedComponents<Selected>,
+    mut outline_query: Query<(Entity, &ChildOf, &mut Transform), With<SelectionOutline>>,
+    mut outline_assets: Local<Option<(Handle<Mesh>, Handle<StandardMaterial>)>>,
+) {
+    let (outline_mesh, outline_material) = outline_assets.get_or_insert_with(|| {
+        (
+            meshes.add(Sphere::new(PARTICLE_RADIUS)),
+            materials.add(StandardMaterial {
+                base_color: selection_style.outline_color,
+                unlit: true,
+                // Only the inside faces are drawn, so the particle stays visible within the shell
+                cull_mode: Some(bevy::render::render_resource::Face::Front),
+                ..default()
+            }),
+        )
+    }).clone();
+    
+    // Apply style changes to existing outlines
+    if selection_style.is_changed() {
+        if let Some(material) = materials.get_mut(&outline_material) {
+            material.base_color = selection_style.outline_color;
+        }
+        for (_, child_of, mut transform) in outline_query.iter_mut() {
+            let shape = shape_query.get(child_of.parent()).copied().unwrap_or_default();
+            transform.scale = Vec3::splat(selection_style.outline_scale * shape.bounding_radius_factor());
+        }
+    }
+    
+    // Remove outlines of deselected particles (despawned particles take their outline with them)
+    let removed: std::collections::HashSet<Entity> = removed_selections.read().collect();
+    if !removed.is_empty() {
+        for (outline, child_of, _) in outline_query.iter() {
+            if removed.contains(&child_of.parent()) {
+                commands.entity(outline).despawn();
             }
         }
     }
+    
+    // The shell encloses the shape's bounding sphere (e.g. cube corners)
+    for (entity, shape) in added_query.iter() {
+        let scale = selection_style.outline_scale * shape.copied().unwrap_or_default().bounding_radius_factor();
+        commands.spawn((
+            Mesh3d(outline_mesh.clone()),
+            MeshMaterial3d(outline_material.clone()),
+            Transform::from_scale(Vec3::splat(scale)),
+            SelectionOutline,
+            ChildOf(entity),
+        ));
+    }
+}
+
+/// Point where the cursor ray (through the camera viewport under the cursor) hits the
+/// horizontal plane at the given height
+pub fn cursor_plane_point(
+    window: &Window,
+    camera_query: &Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    height: f32,
+) -> Option<Vec3> {
+    let cursor_pos = window.cursor_position()?;
+    let cursor_physical = cursor_pos * window.scale_factor() as f32;
+    
+    let (camera, camera_transform) = camera_query.iter().find(|(camera, _)| {
+        camera.viewport.as_ref().is_some_and(|viewport| {
+            let viewport_start = viewport.physical_position.as_vec2();
+            let viewport_end = viewport_start + viewport.physical_size.as_vec2();
+            cursor_physical.x >= viewport_start.x && cursor_physical.x < viewport_end.x &&
+            cursor_physical.y >= viewport_start.y && cursor_physical.y < viewport_end.y
+        })
+    })?;
+    
+    let ray = camera.viewport_to_world(camera_transform, cursor_pos).ok()?;
+    let distance = ray.intersect_plane(Vec3::Y * height, InfinitePlane3d::new(Vec3::Y))?;
+    Some(ray.get_point(distance))
+}
+
+/// System to select all particles within the brush radius of the clicked ground point
+/// The brush is a vertical cylinder: only the horizontal (XZ) distance counts, so particles
+/// above the ground circle are selected whatever their height
+pub fn handle_brush_selection(
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    particle_query: Query<&Visibility, With<Particle>>,
+    particle_positions: Res<ParticlePositions>,
+    brush_state: Res<BrushSelectState>,
+    button_state: Res<MouseButtonState>,
+    mut commands: Commands,
+    mut selection_state: ResMut<ParticleSelectionState>,
+    mut selection_changed: MessageWriter<SelectionChanged>,
+) {
+    // Only on release (transition from pressed to not pressed)
+    if !brush_state.is_active || !button_state.left_was_pressed || button_state.left_pressed {
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    
+    // A release after dragging (e.g. rotating the camera) is not a brush click
+    let (Some(cursor_pos), Some(press_pos)) = (window.cursor_position(), button_state.left_press_position) else { return };
+    if (cursor_pos - press_pos).length() >= MIN_DRAG_DISTANCE {
+        return;
+    }
+    
+    let Some(center) = cursor_plane_point(window, &camera_query, 0.0) else { return };
+    let radius_squared = brush_state.radius * brush_state.radius;
+    let mut added = Vec::new();
+    
+    for (entity, position) in particle_positions.current_positions.iter() {
+        // Hidden particles cannot be brush-selected
+        let Ok(visibility) = particle_query.get(*entity) else { continue };
+        if *visibility == Visibility::Hidden || position.xz().distance_squared(center.xz()) > radius_squared {
+            continue;
+        }
+        if selection_state.selected_particles.insert(*entity) {
+            commands.entity(*entity).insert(Selected);
+            added.push(*entity);
+        }
+    }
+    
+    if !added.is_empty() {
+        selection_changed.write(SelectionChanged { added, removed: Vec::new() });
+    }
+}
+
+/// System to draw the brush radius on the ground plane under the cursor while the tool is active
+pub fn draw_brush_cursor(
+    mut gizmos: Gizmos,
+    windows: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    brush_state: Res<BrushSelectState>,
+) {
+    if !brush_state.is_active {
+        return;
+    }
+    
+    let Ok(window) = windows.single() else { return };
+    let Some(center) = cursor_plane_point(window, &camera_query, 0.0) else { return };
+    
+    // Gizmo circles lie in the XY plane, rotate them flat onto the ground
+    let isometry = Isometry3d::new(center, Quat::from_rotation_x(std::f32::consts::FRAC_PI_2));
+    gizmos.circle(isometry, brush_state.radius, BRUSH_COLOR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::camera::CameraProjection;
+    use bevy::window::WindowResolution;
+    use crate::components::ParticleBaseColor;
+    use crate::systems::test_harness::*;
+    
+    const WINDOW_SIZE: Vec2 = Vec2::new(800.0, 600.0);
+    
+    /// Window and a full-window perspective camera at `transform`, with the projection matrix
+    /// the render plugin would compute
+    fn spawn_view(app: &mut App, transform: Transform) {
+        let persp = PerspectiveProjection { aspect_ratio: WINDOW_SIZE.x / WINDOW_SIZE.y, ..default() };
+        let mut camera = Camera::default();
+        camera.computed.clip_from_view = persp.get_clip_from_view();
+        app.world_mut().spawn(Window {
+            resolution: WindowResolution::new(WINDOW_SIZE.x as u32, WINDOW_SIZE.y as u32),
+            ..default()
+        });
+        app.world_mut().spawn((Camera3d::default(), camera, Projection::Perspective(persp), transform, GlobalTransform::from(transform)));
+    }
+    
+    /// Release a box drag between two logical window positions
+    fn drag_box(app: &mut App, start: Vec2, end: Vec2) {
+        let mut selection_box_state = app.world_mut().resource_mut::<SelectionBoxState>();
+        selection_box_state.start_position = Some(start);
+        selection_box_state.current_position = Some(end);
+        app.update();
+    }
+    
+    #[test]
+    fn selecting_and_deselecting_keeps_the_base_color() {
+        let mut app = test_app();
+        app.add_systems(Update, (process_selection_box, update_selection_outlines).chain());
+        spawn_view(&mut app, Transform::from_xyz(0.0, 1.5, 10.0).looking_at(Vec3::new(0.0, 1.5, 0.0), Vec3::Y));
+        let particles = spawn_particles(&mut app, &[Vec3::new(0.0, 1.5, 0.0)]);
+        let red = app.world_mut().resource_mut::<Assets<StandardMaterial>>().add(Color::srgb(1.0, 0.0, 0.0));
+        app.world_mut().entity_mut(particles[0]).insert((MeshMaterial3d(red.clone()), ParticleBaseColor(red.clone())));
+        
+        drag_box(&mut app, Vec2::ZERO, WINDOW_SIZE);
+        assert!(app.world().get::<Selected>(particles[0]).is_some());
+        // A click without dragging clears the selection
+        drag_box(&mut app, WINDOW_SIZE * 0.5, WINDOW_SIZE * 0.5);
+        assert!(app.world().get::<Selected>(particles[0]).is_none());
+        
+        let material = app.world().get::<MeshMaterial3d<StandardMaterial>>(particles[0]).expect("particle material");
+        assert_eq!(material.0, red);
+        let materials = app.world().resource::<Assets<StandardMaterial>>();
+        assert_eq!(materials.get(&red).map(|material| material.base_color), Some(Color::srgb(1.0, 0.0, 0.0)));
+    }
+    
+    #[test]
+    fn top_view_box_selection_matches_the_screen_layout() {
+        let mut app = test_app();
+        app.add_systems(Update, process_selection_box);
+        // Top view: looking down with -Z up on screen, so +X is right and +Z is down
+        spawn_view(&mut app, Transform::from_xyz(0.0, 10.0, 0.0).looking_at(Vec3::ZERO, Vec3::NEG_Z));
+        let particles = spawn_particles(&mut app, &[
+            Vec3::new(-2.0, 1.0, -2.0),
+            Vec3::new(2.0, 1.0, -2.0),
+            Vec3::new(-2.0, 1.0, 2.0),
+            Vec3::new(2.0, 1.0, 2.0),
+        ]);
+        let selected = |app: &App| particles.iter().map(|entity| app.world().get::<Selected>(*entity).is_some()).collect::<Vec<_>>();
+        
+        // Top-left quarter of the screen holds the -X, -Z particle
+        drag_box(&mut app, Vec2::ZERO, WINDOW_SIZE * 0.5);
+        assert_eq!(selected(&app), [true, false, false, false]);
+        
+        // Bottom-right quarter adds the +X, +Z particle
+        drag_box(&mut app, WINDOW_SIZE * 0.5, WINDOW_SIZE);
+        assert_eq!(selected(&app), [true, false, false, true]);
+    }
+    
+    #[test]
+    fn box_selecting_many_particles_adds_no_material_per_particle() {
+        let mut app = test_app();
+        app.add_systems(Update, (process_selection_box, update_selection_outlines).chain());
+        spawn_view(&mut app, Transform::from_xyz(0.0, 1.5, 10.0).looking_at(Vec3::new(0.0, 1.5, 0.0), Vec3::Y));
+        let positions: Vec<Vec3> = (0..2000)
+            .map(|index| Vec3::new((index % 50) as f32 * 0.08 - 2.0, 1.0 + (index / 50) as f32 * 0.025, 0.0))
+            .collect();
+        spawn_particles(&mut app, &positions);
+        let material_count = app.world().resource::<Assets<StandardMaterial>>().len();
+        
+        drag_box(&mut app, Vec2::ZERO, WINDOW_SIZE);
+        
+        assert_eq!(app.world().resource::<ParticleSelectionState>().selected_particles.len(), positions.len());
+        // Only the shared outline material is added
+        assert!(app.world().resource::<Assets<StandardMaterial>>().len() <= material_count + 1);
+    }
 }
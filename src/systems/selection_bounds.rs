@@ -2,58 +2,100 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{ParticleSelectionState, SelectionBoundingBox, Particle};
+use crate::components::{ParticleSelectionState, ParticlePositions, SelectionBoundingBox, SelectionStats, SelectionBoundsState, SelectionBoundsShape};
+use crate::constants::{CENTROID_MARKER_SIZE, COLOR_RED, COLOR_GREEN, COLOR_BLUE};
 
 const SELECTION_BOX_LINE_RADIUS: f32 = 0.01;
 const SELECTION_BOX_COLOR: Color = Color::srgb(0.7, 0.7, 0.7); // Light gray
+const SELECTION_SPHERE_COLOR: Color = Color::srgba(0.7, 0.7, 0.7, 0.15); // Faint light gray
 
-/// System to update the selection bounding box wireframe
+/// System to recompute the selection centroid and AABB when the selection or positions change
+pub fn update_selection_stats(
+    selection_state: Res<ParticleSelectionState>,
+    particle_positions: Res<ParticlePositions>,
+    mut selection_stats: ResMut<SelectionStats>,
+) {
+    if !selection_state.is_changed() && !particle_positions.is_changed() {
+        return;
+    }
+    
+    let mut stats = SelectionStats {
+        count: 0,
+        centroid: Vec3::ZERO,
+        min: Vec3::splat(f32::MAX),
+        max: Vec3::splat(f32::MIN),
+        radius: 0.0,
+    };
+    for entity in selection_state.selected_particles.iter() {
+        if let Some(&pos) = particle_positions.current_positions.get(entity) {
+            stats.count += 1;
+            stats.centroid += pos;
+            stats.min = stats.min.min(pos);
+            stats.max = stats.max.max(pos);
+        }
+    }
+    
+    if stats.count > 0 {
+        stats.centroid /= stats.count as f32;
+        // Second pass for the bounding sphere radius around the centroid
+        stats.radius = selection_state.selected_particles.iter()
+            .filter_map(|entity| particle_positions.current_positions.get(entity))
+            .map(|pos| pos.distance(stats.centroid))
+            .fold(0.0, f32::max);
+    } else {
+        stats = SelectionStats::default();
+    }
+    
+    // Only write when something changed so consumers can rely on change detection
+    selection_stats.set_if_neq(stats);
+}
+
+/// System to update the selection bounding box wireframe (or bounding sphere)
 pub fn update_selection_bounding_box(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    selection_state: Res<ParticleSelectionState>,
-    particle_query: Query<&Transform, (With<Particle>, Without<SelectionBoundingBox>)>,
+    selection_stats: Res<SelectionStats>,
+    bounds_state: Res<SelectionBoundsState>,
     bounding_box_query: Query<Entity, With<SelectionBoundingBox>>,
 ) {
+    // Rebuild only when the selection bounds or the shape change
+    if !selection_stats.is_changed() && !bounds_state.is_changed() {
+        return;
+    }
+    
     // Remove existing bounding box if no particles are selected
-    if selection_state.selected_particles.is_empty() {
+    if selection_stats.count == 0 {
         for entity in bounding_box_query.iter() {
             commands.entity(entity).despawn();
         }
         return;
     }
     
-    // Calculate bounding box from selected particles
-    let mut min_x = f32::MAX;
-    let mut max_x = f32::MIN;
-    let mut min_y = f32::MAX;
-    let mut max_y = f32::MIN;
-    let mut min_z = f32::MAX;
-    let mut max_z = f32::MIN;
-    
-    let mut has_particles = false;
-    for entity in selection_state.selected_particles.iter() {
-        if let Ok(transform) = particle_query.get(*entity) {
-            let pos = transform.translation;
-            min_x = min_x.min(pos.x);
-            max_x = max_x.max(pos.x);
-            min_y = min_y.min(pos.y);
-            max_y = max_y.max(pos.y);
-            min_z = min_z.min(pos.z);
-            max_z = max_z.max(pos.z);
-            has_particles = true;
-        }
-    }
-    
-    if !has_particles {
-        // Remove bounding box if no valid particles found
+    if bounds_state.shape == SelectionBoundsShape::Sphere {
         for entity in bounding_box_query.iter() {
             commands.entity(entity).despawn();
         }
+        
+        // Transparent sphere around the centroid, padded like the box
+        let sphere_material = materials.add(StandardMaterial {
+            base_color: SELECTION_SPHERE_COLOR,
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        });
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere::new(selection_stats.radius + 0.1))),
+            MeshMaterial3d(sphere_material),
+            Transform::from_translation(selection_stats.centroid),
+            SelectionBoundingBox,
+        ));
         return;
     }
     
+    let (mut min_x, mut min_y, mut min_z) = selection_stats.min.into();
+    let (mut max_x, mut max_y, mut max_z) = selection_stats.max.into();
+    
     // Add padding to the bounding box
     let padding = 0.1;
     min_x -= padding;
@@ -184,3 +226,20 @@ pub fn update_selection_bounding_box(
         SelectionBoundingBox,
     ));
 }
+
+/// System to draw a crosshair (X red, Y green, Z blue) at the selection centroid
+/// Immediate-mode gizmos follow the cached centroid every frame
+pub fn draw_selection_centroid(
+    mut gizmos: Gizmos,
+    bounds_state: Res<SelectionBoundsState>,
+    selection_stats: Res<SelectionStats>,
+) {
+    if !bounds_state.show_centroid || selection_stats.count == 0 {
+        return;
+    }
+    
+    let center = selection_stats.centroid;
+    for (axis, color) in [(Vec3::X, COLOR_RED), (Vec3::Y, COLOR_GREEN), (Vec3::Z, COLOR_BLUE)] {
+        gizmos.line(center - axis * CENTROID_MARKER_SIZE, center + axis * CENTROID_MARKER_SIZE, color);
+    }
+}
@@ -2,38 +2,75 @@
 // Copyright (C) 2026 vecnode
 
 use bevy::prelude::*;
-use crate::components::{ParticleSelectionState, SelectionTransformState, Particle, ParticlePositions};
+use crate::components::{ParticleSelectionState, SelectionChanged, SelectionTransformState, Particle, ParticlePositions, Locked, ParticleBoundsState, ParticleRng, ParticleGroupState};
+use crate::constants::NUDGE_FAST_MULTIPLIER;
+use bevy_egui::EguiContexts;
+use rand::Rng;
+
+/// Reset selection transform values to identity and drop stored originals
+/// The scale pivot choice is a setting, not part of the transform, so it is kept
+pub fn reset_selection_transform(transform_state: &mut SelectionTransformState) {
+    transform_state.original_selection_positions.clear();
+    transform_state.position_offset = Vec3::ZERO;
+    transform_state.scale = Vec3::ONE;
+    transform_state.previous_position_offset = Vec3::ZERO;
+    transform_state.previous_scale = Vec3::ONE;
+}
+
+/// Shift a selected particle's stored original by a step some other system moved the particle
+/// The continuous offset/scale transform rebuilds selected positions from the originals every frame,
+/// so motion systems running after it shift the original too, or the next frame would undo their step.
+/// Particles without an original (not selected) are left alone
+pub fn shift_selection_original(transform_state: &mut SelectionTransformState, entity: Entity, step: Vec3) {
+    if let Some(original_pos) = transform_state.original_selection_positions.get_mut(&entity) {
+        *original_pos += step;
+    }
+}
+
+/// Store a particle's current world position as its base position (normalized against the bounds)
+/// Undoes the group transform (base * scale + offset) first, so group and bounds changes keep it
+pub fn bake_base_position(
+    entity: Entity,
+    position: Vec3,
+    particle_positions: &mut ParticlePositions,
+    bounds_state: &ParticleBoundsState,
+    group_state: &ParticleGroupState,
+) {
+    // Axes scaled to zero cannot be inverted, leave them unscaled
+    let scale = Vec3::select(group_state.scale.abs().cmpgt(Vec3::splat(f32::EPSILON)), group_state.scale, Vec3::ONE);
+    let base_world = (position - group_state.offset) / scale;
+    particle_positions.base_positions.insert(entity, bounds_state.world_to_normalized(base_world));
+    particle_positions.current_positions.insert(entity, position);
+}
 
 /// System to update original positions when selection changes and reset transforms
+/// A transform applied to the previous selection is baked first, so the moved particles stay moved
 pub fn update_selection_original_positions(
     mut transform_state: ResMut<SelectionTransformState>,
     selection_state: Res<ParticleSelectionState>,
-    particle_query: Query<(Entity, &Transform), With<Particle>>,
+    particle_query: Query<(&Transform, Has<Locked>), With<Particle>>,
     mut particle_positions: ResMut<ParticlePositions>,
+    mut selection_changed: MessageReader<SelectionChanged>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
 ) {
-    // Create a hash of the current selection to detect changes
-    use std::collections::hash_map::DefaultHasher;
-    use std::hash::{Hash, Hasher};
-    let mut hasher = DefaultHasher::new();
-    let mut sorted_entities: Vec<Entity> = selection_state.selected_particles.iter().copied().collect();
-    sorted_entities.sort();
-    sorted_entities.hash(&mut hasher);
-    let current_selection_hash = hasher.finish();
-    
-    // Check if selection changed
-    if current_selection_hash != transform_state.previous_selection_hash {
-        // Selection changed - update original positions and reset transforms
-        transform_state.original_selection_positions.clear();
-        transform_state.previous_selection_hash = current_selection_hash;
+    // Several changes in one frame still need only one reset
+    if selection_changed.read().count() > 0 {
+        // The particles that were transformed are the ones with stored originals
+        if transform_state.position_offset != Vec3::ZERO || transform_state.scale != Vec3::ONE {
+            for entity in transform_state.original_selection_positions.keys() {
+                if let Ok((transform, false)) = particle_query.get(*entity) {
+                    bake_base_position(*entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+                }
+            }
+        }
         
-        // Reset transform values to defaults when selection changes
-        transform_state.position_offset = Vec3::ZERO;
-        transform_state.scale = Vec3::ONE;
-        transform_state.previous_position_offset = Vec3::ZERO;
-        transform_state.previous_scale = Vec3::ONE;
+        // Selection changed - reset transform values to defaults and update original positions
+        // (the current, already transformed positions become the new originals)
+        reset_selection_transform(&mut transform_state);
         
         for entity in selection_state.selected_particles.iter() {
-            if let Ok((_, transform)) = particle_query.get(*entity) {
+            if let Ok((transform, _)) = particle_query.get(*entity) {
                 // Store current position as original (before any transforms)
                 let current_pos = transform.translation;
                 transform_state.original_selection_positions.insert(*entity, current_pos);
@@ -46,8 +83,10 @@ pub fn update_selection_original_positions(
 
 /// System to apply position offset and scale to selected particles only
 /// This always runs to ensure transforms are applied whenever selection or values change
+/// Scale is applied about the chosen pivot (centroid of the originals by default), the offset after it
+/// Locked particles stay in the selection but are not moved
 pub fn update_selection_transform(
-    mut particle_query: Query<(Entity, &mut Transform), With<Particle>>,
+    mut particle_query: Query<(Entity, &mut Transform), (With<Particle>, Without<Locked>)>,
     mut transform_state: ResMut<SelectionTransformState>,
     mut particle_positions: ResMut<ParticlePositions>,
     selection_state: Res<ParticleSelectionState>,
@@ -61,26 +100,29 @@ pub fn update_selection_transform(
             transform_state.previous_scale = transform_state.scale;
         }
         
-        // Calculate center from original positions
-        let mut center = Vec3::ZERO;
-        let mut count = 0;
-        
-        for entity in selection_state.selected_particles.iter() {
-            if let Some(&original_pos) = transform_state.original_selection_positions.get(entity) {
-                center += original_pos;
-                count += 1;
+        // Scale pivot: centroid of the original positions, the world origin or a custom point
+        let center = transform_state.fixed_pivot().unwrap_or_else(|| {
+            let mut center = Vec3::ZERO;
+            let mut count = 0;
+            
+            for entity in selection_state.selected_particles.iter() {
+                if let Some(&original_pos) = transform_state.original_selection_positions.get(entity) {
+                    center += original_pos;
+                    count += 1;
+                }
             }
-        }
-        
-        if count > 0 {
-            center /= count as f32;
-        }
+            
+            if count > 0 {
+                center /= count as f32;
+            }
+            center
+        });
         
         // Apply transform only to selected particles
         for entity in selection_state.selected_particles.iter() {
             if let Ok((_, mut transform)) = particle_query.get_mut(*entity) {
                 if let Some(&original_pos) = transform_state.original_selection_positions.get(entity) {
-                    // Get position relative to original center
+                    // Get position relative to the pivot
                     let relative_pos = original_pos - center;
                     
                     // Apply scale (normal distribution)
@@ -90,7 +132,7 @@ pub fn update_selection_transform(
                         relative_pos.z * transform_state.scale.z,
                     );
                     
-                    // Apply position offset and restore center
+                    // Apply position offset and restore the pivot
                     transform.translation = center + scaled_relative + transform_state.position_offset;
                     
                     // Update stored position
@@ -100,3 +142,284 @@ pub fn update_selection_transform(
         }
     }
 }
+
+/// System to flatten the selection onto a plane: sets the chosen coordinate of every
+/// selected particle to the selection mean for that axis
+/// Works on the stored originals so the continuous offset/scale transform keeps the result,
+/// and bakes the base positions so bounds and group changes keep it too
+pub fn handle_selection_flatten(
+    mut particle_query: Query<&mut Transform, (With<Particle>, Without<Locked>)>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+) {
+    let Some(axis) = transform_state.flatten_requested.take() else { return };
+    
+    // Flattening needs at least two particles to be meaningful
+    let selected: Vec<Entity> = selection_state.selected_particles.iter()
+        .filter(|entity| transform_state.original_selection_positions.contains_key(entity))
+        .copied()
+        .collect();
+    if selected.len() < 2 || axis > 2 {
+        return;
+    }
+    
+    // Mean of the original positions along the axis (unchanged by flattening)
+    let mean = selected.iter()
+        .map(|entity| transform_state.original_selection_positions[entity][axis])
+        .sum::<f32>() / selected.len() as f32;
+    
+    let position_offset = transform_state.position_offset;
+    for entity in selected.iter() {
+        if let Some(original_pos) = transform_state.original_selection_positions.get_mut(entity) {
+            original_pos[axis] = mean;
+        }
+        
+        // Scaled relative coordinate is now zero, so the axis value is the mean plus offset
+        if let Ok(mut transform) = particle_query.get_mut(*entity) {
+            transform.translation[axis] = mean + position_offset[axis];
+            bake_base_position(*entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+        }
+    }
+}
+
+/// System to scatter the selected particles to new random positions within the particle bounds
+/// Uses the seeded RNG and updates base positions so bounds resizing keeps working
+pub fn handle_selection_scatter(
+    mut particle_query: Query<(&mut Transform, Has<Locked>), With<Particle>>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    mut particle_rng: ResMut<ParticleRng>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+) {
+    if !transform_state.scatter_requested {
+        return;
+    }
+    transform_state.scatter_requested = false;
+    
+    // Scattered positions become the new originals, so start from an identity transform
+    reset_selection_transform(&mut transform_state);
+    
+    let selected = selection_state.sorted();
+    
+    for entity in selected {
+        let Ok((mut transform, is_locked)) = particle_query.get_mut(entity) else { continue };
+        
+        // Locked particles keep their place
+        if !is_locked {
+            let normalized = Vec3::new(
+                particle_rng.rng.gen_range(0.0..=1.0),
+                particle_rng.rng.gen_range(0.0..=1.0),
+                particle_rng.rng.gen_range(0.0..=1.0),
+            );
+            
+            // Y range starts at the bounds floor, so the Y-min is respected
+            transform.translation = bounds_state.normalized_to_world(normalized);
+            bake_base_position(entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+        }
+        
+        transform_state.original_selection_positions.insert(entity, transform.translation);
+    }
+}
+
+/// System to nudge the selection with the arrow keys (X/Z) and PageUp/PageDown (Y)
+/// Shift uses a larger step; ignored while an egui widget has keyboard focus
+/// Base positions follow the nudge, so bounds and group changes keep it
+pub fn handle_selection_nudge(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut contexts: EguiContexts,
+    mut particle_query: Query<&mut Transform, (With<Particle>, Without<Locked>)>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+) {
+    if selection_state.selected_particles.is_empty() {
+        return;
+    }
+    if let Ok(ctx) = contexts.ctx_mut()
+        && ctx.wants_keyboard_input()
+    {
+        return;
+    }
+    
+    let mut direction = Vec3::ZERO;
+    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
+        direction.x += 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+        direction.z -= 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+        direction.z += 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::PageUp) {
+        direction.y += 1.0;
+    }
+    if keyboard_input.just_pressed(KeyCode::PageDown) {
+        direction.y -= 1.0;
+    }
+    if direction == Vec3::ZERO {
+        return;
+    }
+    
+    let fast = keyboard_input.pressed(KeyCode::ShiftLeft) || keyboard_input.pressed(KeyCode::ShiftRight);
+    let step = if fast { transform_state.nudge_step * NUDGE_FAST_MULTIPLIER } else { transform_state.nudge_step };
+    let delta = direction * step;
+    
+    for entity in selection_state.selected_particles.iter() {
+        let Ok(mut transform) = particle_query.get_mut(*entity) else { continue };
+        transform.translation += delta;
+        bake_base_position(*entity, transform.translation, &mut particle_positions, &bounds_state, &group_state);
+        shift_selection_original(&mut transform_state, *entity, delta);
+    }
+}
+
+/// System to move the single selected particle to an exact position typed in the Inspector
+/// The stored original is set so the continuous offset transform lands on the typed position,
+/// and the base position so bounds and group changes keep it
+pub fn handle_exact_position(
+    mut particle_query: Query<&mut Transform, (With<Particle>, Without<Locked>)>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+) {
+    let Some(position) = transform_state.exact_position_requested.take() else { return };
+    
+    // Only meaningful with exactly one particle selected
+    if selection_state.selected_particles.len() != 1 {
+        return;
+    }
+    let Some(&entity) = selection_state.selected_particles.iter().next() else { return };
+    let Ok(mut transform) = particle_query.get_mut(entity) else { return };
+    
+    transform.translation = position;
+    bake_base_position(entity, position, &mut particle_positions, &bounds_state, &group_state);
+    // A single particle is its own centroid, so scale has no effect there and only the offset applies;
+    // about another pivot the scale is undone too (axes scaled to zero cannot be inverted)
+    let original_pos = match transform_state.fixed_pivot() {
+        None => position - transform_state.position_offset,
+        Some(pivot) => {
+            let scale = Vec3::select(transform_state.scale.abs().cmpgt(Vec3::splat(f32::EPSILON)), transform_state.scale, Vec3::ONE);
+            pivot + (position - transform_state.position_offset - pivot) / scale
+        }
+    };
+    transform_state.original_selection_positions.insert(entity, original_pos);
+}
+
+/// System to bake the current selection offset/scale into the particles
+/// The transformed positions become the new originals and base positions, and the
+/// transform goes back to identity, so the change survives deselection and bounds resizing
+pub fn handle_apply_selection_transform(
+    particle_query: Query<(&Transform, Has<Locked>), With<Particle>>,
+    mut transform_state: ResMut<SelectionTransformState>,
+    mut particle_positions: ResMut<ParticlePositions>,
+    selection_state: Res<ParticleSelectionState>,
+    bounds_state: Res<ParticleBoundsState>,
+    group_state: Res<ParticleGroupState>,
+) {
+    if !transform_state.apply_requested {
+        return;
+    }
+    transform_state.apply_requested = false;
+    
+    for entity in selection_state.selected_particles.iter() {
+        let Ok((transform, is_locked)) = particle_query.get(*entity) else { continue };
+        let position = transform.translation;
+        transform_state.original_selection_positions.insert(*entity, position);
+        
+        // Locked particles were never moved by the transform, their base stays valid
+        if is_locked {
+            continue;
+        }
+        
+        bake_base_position(*entity, position, &mut particle_positions, &bounds_state, &group_state);
+    }
+    
+    transform_state.position_offset = Vec3::ZERO;
+    transform_state.scale = Vec3::ONE;
+    transform_state.previous_position_offset = Vec3::ZERO;
+    transform_state.previous_scale = Vec3::ONE;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::test_harness::*;
+    
+    #[test]
+    fn selection_offset_and_scale_move_only_selected_particles() {
+        let mut app = test_app();
+        app.add_systems(Update, (update_selection_original_positions, update_selection_transform).chain());
+        let particles = spawn_particles(&mut app, &[Vec3::new(-1.0, 1.0, 0.0), Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 3.0)]);
+        set_selection(&mut app, &particles[..2]);
+        app.update();
+        
+        {
+            let mut transform_state = app.world_mut().resource_mut::<SelectionTransformState>();
+            transform_state.position_offset = Vec3::new(0.0, 0.5, 0.0);
+            transform_state.scale = Vec3::splat(2.0);
+        }
+        run_updates(&mut app, 3);
+        
+        // Scaled about the selection centroid (0, 1, 0), then offset
+        assert_near(position(&app, particles[0]), Vec3::new(-2.0, 1.5, 0.0));
+        assert_near(position(&app, particles[1]), Vec3::new(2.0, 1.5, 0.0));
+        assert_near(position(&app, particles[2]), Vec3::new(0.0, 1.0, 3.0));
+    }
+    
+    #[test]
+    fn applied_offset_persists_after_deselect_and_reselect() {
+        let mut app = test_app();
+        app.add_systems(Update, (update_selection_original_positions, handle_apply_selection_transform, update_selection_transform).chain());
+        let particles = spawn_particles(&mut app, &[Vec3::new(-1.0, 1.5, 0.0), Vec3::new(1.0, 1.5, 0.0)]);
+        set_selection(&mut app, &particles[..1]);
+        app.update();
+        app.world_mut().resource_mut::<SelectionTransformState>().position_offset = Vec3::new(1.0, 0.0, 2.0);
+        app.update();
+        app.world_mut().resource_mut::<SelectionTransformState>().apply_requested = true;
+        app.update();
+        assert_eq!(app.world().resource::<SelectionTransformState>().position_offset, Vec3::ZERO);
+        
+        set_selection(&mut app, &[]);
+        app.update();
+        set_selection(&mut app, &particles[..1]);
+        run_updates(&mut app, 2);
+        
+        let moved = Vec3::new(0.0, 1.5, 2.0);
+        assert_near(position(&app, particles[0]), moved);
+        assert_near(position(&app, particles[1]), Vec3::new(1.0, 1.5, 0.0));
+        // The base position was baked too, so bounds and group changes keep the move
+        let base = app.world().resource::<ParticlePositions>().base_positions[&particles[0]];
+        assert_near(app.world().resource::<ParticleBoundsState>().normalized_to_world(base), moved);
+    }
+    
+    #[test]
+    fn adding_to_a_transformed_selection_keeps_the_moved_particles() {
+        let mut app = test_app();
+        app.add_systems(Update, (update_selection_original_positions, update_selection_transform).chain());
+        let particles = spawn_particles(&mut app, &[Vec3::new(-1.0, 1.5, 0.0), Vec3::new(1.0, 1.5, 0.0), Vec3::new(0.0, 1.5, 3.0)]);
+        set_selection(&mut app, &particles[..2]);
+        app.update();
+        app.world_mut().resource_mut::<SelectionTransformState>().position_offset = Vec3::new(0.0, 0.0, 1.0);
+        app.update();
+        
+        set_selection(&mut app, &particles);
+        run_updates(&mut app, 2);
+        
+        assert_near(position(&app, particles[0]), Vec3::new(-1.0, 1.5, 1.0));
+        assert_near(position(&app, particles[1]), Vec3::new(1.0, 1.5, 1.0));
+        assert_near(position(&app, particles[2]), Vec3::new(0.0, 1.5, 3.0));
+        assert_eq!(app.world().resource::<SelectionTransformState>().position_offset, Vec3::ZERO);
+    }
+}
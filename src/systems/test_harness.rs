@@ -0,0 +1,109 @@
+// systems/test_harness.rs
+// Copyright (C) 2026 vecnode
+
+// Headless App for testing particle systems without a window or GPU
+// Build the App with `test_app`, add the systems under test to `Update`, spawn particles with
+// `spawn_particles`, then step it with `run_updates` and check `position`
+
+use bevy::prelude::*;
+use bevy::asset::AssetPlugin;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::time::TimeUpdateStrategy;
+use crate::components::*;
+use crate::constants::COLOR_WHITE;
+
+/// Fixed frame time, so motion after N updates is exactly N steps
+pub const FRAME_SECONDS: f32 = 1.0 / 60.0;
+
+/// App with MinimalPlugins, the mesh and material assets and the particle resources
+/// The first update is already run: Time reports a zero delta on it, every later update advances FRAME_SECONDS
+pub fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        // Registered by the visibility plugin in the app; hidden particles are skipped by selection
+        .register_required_components::<Mesh3d, Visibility>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(std::time::Duration::from_secs_f32(FRAME_SECONDS)))
+        .init_resource::<ButtonInput<KeyCode>>()
+        .init_resource::<StartupParticleCount>()
+        .init_resource::<ParticleSelectionState>()
+        .init_resource::<ParticlePositions>()
+        .init_resource::<Motion1State>()
+        .init_resource::<TrajectoryState>()
+        .init_resource::<TrajectoryStyle>()
+        .init_resource::<SelectionBoxState>()
+        .init_resource::<SelectionStyle>()
+        .init_resource::<ParticleBoundsState>()
+        .init_resource::<ParticleGroupState>()
+        .init_resource::<ParticleCreationState>()
+        .init_resource::<SelectionTransformState>()
+        .init_resource::<ParticleRadius>()
+        .init_resource::<ParticleShapeState>()
+        .init_resource::<LayerState>()
+        .init_resource::<ParticleRng>()
+        .init_resource::<MaxParticles>()
+        .init_resource::<PendingSpawns>()
+        .init_resource::<AttractorState>()
+        .init_resource::<CameraViewChanged>()
+        .add_message::<SelectionChanged>();
+    app.update();
+    app
+}
+
+/// Spawn particles at the given positions through spawn_single_particle, sharing one white material
+pub fn spawn_particles(app: &mut App, positions: &[Vec3]) -> Vec<Entity> {
+    let positions = positions.to_vec();
+    app.world_mut().run_system_once(move |
+        mut commands: Commands,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+        mut particle_positions: ResMut<ParticlePositions>,
+        spawn_settings: crate::systems::SpawnSettings,
+    | -> Vec<Entity> {
+        let material = materials.add(COLOR_WHITE);
+        positions.iter().map(|position| {
+            crate::systems::spawn_single_particle(
+                &mut commands,
+                &mut particle_positions,
+                *position,
+                ParticleShape::default(),
+                &Handle::default(),
+                &material,
+                &spawn_settings,
+            )
+        }).collect()
+    }).expect("spawning particles")
+}
+
+/// Replace the selection, writing the SelectionChanged message the UI and mouse selection write
+pub fn set_selection(app: &mut App, entities: &[Entity]) {
+    let world = app.world_mut();
+    let previous = world.resource::<ParticleSelectionState>().sorted();
+    let removed: Vec<Entity> = previous.iter().copied().filter(|entity| !entities.contains(entity)).collect();
+    let added: Vec<Entity> = entities.iter().copied().filter(|entity| !previous.contains(entity)).collect();
+    for entity in removed.iter() {
+        world.entity_mut(*entity).remove::<Selected>();
+    }
+    for entity in added.iter() {
+        world.entity_mut(*entity).insert(Selected);
+    }
+    world.resource_mut::<ParticleSelectionState>().selected_particles = entities.iter().copied().collect();
+    world.write_message(SelectionChanged { added, removed });
+}
+
+/// Run the given number of frames
+pub fn run_updates(app: &mut App, frames: usize) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+/// World position of a particle
+pub fn position(app: &App, entity: Entity) -> Vec3 {
+    app.world().get::<Transform>(entity).expect("particle transform").translation
+}
+
+/// Assert two positions match within float noise
+pub fn assert_near(actual: Vec3, expected: Vec3) {
+    assert!(actual.abs_diff_eq(expected, 1e-4), "expected {expected}, got {actual}");
+}